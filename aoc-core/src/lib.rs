@@ -0,0 +1,194 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Contract shared by every day's solution.
+///
+/// Parsing is separated from solving so both parts can share the parsed input
+/// and so tooling can measure or validate each phase on its own.
+pub trait Solution {
+    /// Day of the puzzle in the calendar.
+    const NUMBER: u8;
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+
+    /// Puzzle input once parsed.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<String, AnswerError>;
+
+    fn part2(input: &Self::Input) -> Result<String, AnswerError>;
+
+    /// Parses then solves the first part.
+    fn solve(input: &str) -> Result<String, SolveError> {
+        Ok(Self::part1(&Self::parse(input)?)?)
+    }
+
+    /// Parses then solves the second part.
+    fn solve_complex(input: &str) -> Result<String, SolveError> {
+        Ok(Self::part2(&Self::parse(input)?)?)
+    }
+}
+
+/// Malformed puzzle input, located in the original text.
+///
+/// Lines and columns are 1-based, columns count characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Error for the `len` bytes found at byte `offset` of `input`.
+    pub fn at(day: u8, input: &str, offset: usize, len: usize, reason: impl Display) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[offset..offset + len].to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Error for `fragment`, which must be a slice borrowed from `input`.
+    pub fn of(day: u8, input: &str, fragment: &str, reason: impl Display) -> Self {
+        let offset = fragment.as_ptr() as usize - input.as_ptr() as usize;
+        debug_assert!(offset + fragment.len() <= input.len());
+
+        Self::at(day, input, offset, fragment.len(), reason)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day{}, line {}, column {}: {} (`{}`)",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Well-formed input that a part still has no answer for, such as a result
+/// too large for its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerError {
+    pub day: u8,
+    pub part: u8,
+    pub reason: String,
+}
+
+impl AnswerError {
+    pub fn new(day: u8, part: u8, reason: impl Display) -> Self {
+        AnswerError {
+            day,
+            part,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for AnswerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day{}, part {}: {}", self.day, self.part, self.reason)
+    }
+}
+
+impl Error for AnswerError {}
+
+/// Why solving a part from the raw input gave no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Answer(AnswerError),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl From<AnswerError> for SolveError {
+    fn from(err: AnswerError) -> Self {
+        SolveError::Answer(err)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(err) => err.fmt(f),
+            SolveError::Answer(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+#[cfg(test)]
+mod validation {
+    use crate::{AnswerError, ParseError, Solution, SolveError};
+
+    #[test]
+    fn tst_parse_error_location() {
+        let input = "12 34\r\n56 é7x\n";
+        let fragment = &input[input.find('7').unwrap()..][..2];
+        let err = ParseError::of(1, input, fragment, "invalid digit");
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 5);
+        assert_eq!(err.text, "7x");
+        assert_eq!(
+            err.to_string(),
+            "day1, line 2, column 5: invalid digit (`7x`)"
+        );
+    }
+
+    struct Halves;
+
+    impl Solution for Halves {
+        const NUMBER: u8 = 0;
+        const TITLE: &'static str = "Halves";
+
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .parse()
+                .map_err(|err| ParseError::at(0, input, 0, input.len(), err))
+        }
+
+        fn part1(n: &Self::Input) -> Result<String, AnswerError> {
+            match n % 2 {
+                0 => Ok((n / 2).to_string()),
+                _ => Err(AnswerError::new(0, 1, format!("{n} is odd"))),
+            }
+        }
+
+        fn part2(n: &Self::Input) -> Result<String, AnswerError> {
+            Ok(n.to_string())
+        }
+    }
+
+    #[test]
+    fn tst_solve_errors() {
+        assert_eq!(Halves::solve("8"), Ok("4".to_string()));
+        assert!(matches!(Halves::solve("x"), Err(SolveError::Parse(_))));
+
+        let err = Halves::solve("7").unwrap_err();
+        assert_eq!(err, SolveError::Answer(AnswerError::new(0, 1, "7 is odd")));
+        assert_eq!(err.to_string(), "day0, part 1: 7 is odd");
+        assert_eq!(Halves::solve_complex("7"), Ok("7".to_string()));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::Position;
+use std::ops::Add;
+
+/// One of the 8 directions on a grid.
+///
+/// Variants are declared clockwise from `Up`, which the rotations rely on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Orthogonal directions, clockwise from `Up`.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Diagonal directions, clockwise from `UpRight`.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn orthogonal() -> impl Iterator<Item = Direction> {
+        Self::ORTHOGONAL.into_iter()
+    }
+
+    pub fn diagonal() -> impl Iterator<Item = Direction> {
+        Self::DIAGONAL.into_iter()
+    }
+
+    /// Direction of a unit vector, `None` unless both components are in `-1..=1`
+    /// and at least one of them is not 0.
+    pub fn from_delta(dx: isize, dy: isize) -> Option<Direction> {
+        Self::all().find(|dir| dir.dx() == dx && dir.dy() == dy)
+    }
+
+    /// Column delta, positive to the right.
+    pub fn dx(self) -> isize {
+        match self {
+            Direction::Up | Direction::Down => 0,
+            Direction::UpRight | Direction::Right | Direction::DownRight => 1,
+            Direction::DownLeft | Direction::Left | Direction::UpLeft => -1,
+        }
+    }
+
+    /// Row delta, positive downwards.
+    pub fn dy(self) -> isize {
+        match self {
+            Direction::Left | Direction::Right => 0,
+            Direction::DownRight | Direction::Down | Direction::DownLeft => 1,
+            Direction::UpLeft | Direction::Up | Direction::UpRight => -1,
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.dx() != 0 && self.dy() != 0
+    }
+
+    /// Rotates by `eighths` of a turn clockwise.
+    fn turn(self, eighths: usize) -> Direction {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Quarter turn clockwise.
+    pub fn rotate_cw(self) -> Direction {
+        self.turn(2)
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn rotate_ccw(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+}
+
+/// Moves one step, `None` when it would leave the top or left edge.
+///
+/// Use [`crate::Grid::step`] to also stay within the bottom and right edges.
+impl Add<Direction> for Position {
+    type Output = Option<Position>;
+
+    fn add(self, dir: Direction) -> Self::Output {
+        self.offset(dir.dy(), dir.dx())
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::{Direction, Position};
+
+    #[test]
+    fn tst_rotations() {
+        assert_eq!(Direction::Up.rotate_cw(), Direction::Right);
+        assert_eq!(Direction::UpLeft.rotate_cw(), Direction::UpRight);
+        assert_eq!(Direction::Up.rotate_ccw(), Direction::Left);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+
+        for dir in Direction::all() {
+            assert_eq!(dir.rotate_cw().rotate_ccw(), dir);
+            assert_eq!(
+                (dir.opposite().dx(), dir.opposite().dy()),
+                (-dir.dx(), -dir.dy())
+            );
+            assert_eq!(Direction::from_delta(dir.dx(), dir.dy()), Some(dir));
+        }
+        assert_eq!(Direction::from_delta(0, 0), None);
+    }
+
+    #[test]
+    fn tst_add() {
+        let origin = Position::new(0, 0);
+
+        assert_eq!(origin + Direction::DownRight, Some(Position::new(1, 1)));
+        assert_eq!(origin + Direction::Up, None);
+        assert_eq!(origin + Direction::DownLeft, None);
+        assert_eq!(
+            Direction::orthogonal()
+                .filter(|dir| !dir.is_diagonal())
+                .count(),
+            4
+        );
+        assert!(Direction::diagonal().all(Direction::is_diagonal));
+    }
+}
@@ -0,0 +1,395 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+mod direction;
+mod parse;
+mod transform;
+
+pub use direction::Direction;
+pub use parse::{Ragged, Segmentation};
+pub use transform::SubGrid;
+
+/// Cell coordinates in a grid, `row` 0 being the top line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+
+    /// Position moved by a signed offset, `None` when it would go below zero.
+    pub fn offset(self, d_row: isize, d_col: isize) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((row, col): (usize, usize)) -> Self {
+        Position { row, col }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.row, self.col)
+    }
+}
+
+/// Rectangular grid of cells, stored row after row in a single vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells given row after row.
+    ///
+    /// # Panics
+    /// When the number of cells is not `rows * cols`.
+    pub fn from_cells(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            rows * cols,
+            "a {rows}x{cols} grid needs {} cells",
+            rows * cols
+        );
+        Grid { rows, cols, cells }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.cols + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.cols + pos.col])
+    }
+
+    /// Position moved by a signed offset, `None` when it leaves the grid.
+    pub fn offset(&self, pos: Position, d_row: isize, d_col: isize) -> Option<Position> {
+        pos.offset(d_row, d_col).filter(|pos| self.contains(*pos))
+    }
+
+    /// Position one step away in `dir`, `None` when it leaves the grid.
+    pub fn step(&self, pos: Position, dir: Direction) -> Option<Position> {
+        (pos + dir).filter(|pos| self.contains(*pos))
+    }
+
+    /// Cell at a signed offset from `pos`, `None` when it is outside of the grid.
+    pub fn get_offset(&self, pos: Position, d_row: isize, d_col: isize) -> Option<&T> {
+        self.get(self.offset(pos, d_row, d_col)?)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.rows).then(|| &self.cells[row * self.cols..(row + 1) * self.cols])
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = if col < self.cols {
+            &self.cells[col..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.cols.max(1))
+    }
+
+    /// Cells from `start`, moving in `dir` until leaving the grid.
+    pub fn ray(&self, start: Position, dir: Direction) -> impl Iterator<Item = (Position, &T)> {
+        let first = Some(start).filter(|pos| self.contains(*pos));
+
+        std::iter::successors(first, move |pos| self.step(*pos, dir)).map(|pos| (pos, &self[pos]))
+    }
+
+    /// Cells going down and right from `start`.
+    pub fn diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction::DownRight)
+            .map(|(_, value)| value)
+    }
+
+    /// Cells going down and left from `start`.
+    pub fn anti_diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction::DownLeft).map(|(_, value)| value)
+    }
+
+    /// Orthogonal neighbors of `pos` inside of the grid, clockwise from up.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::orthogonal().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Orthogonal and diagonal neighbors of `pos` inside of the grid, clockwise from up.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::all().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn positions(&self) -> Positions {
+        Positions {
+            cols: self.cols,
+            next: 0,
+            end: self.cells.len(),
+        }
+    }
+
+    /// Every cell along with its position, row after row.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        assert!(self.contains(pos), "{pos} is outside of the grid");
+        &self.cells[pos.row * self.cols + pos.col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        assert!(self.contains(pos), "{pos} is outside of the grid");
+        &mut self.cells[pos.row * self.cols + pos.col]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = (Position, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Positions of a grid, row after row.
+#[derive(Debug, Clone)]
+pub struct Positions {
+    cols: usize,
+    next: usize,
+    end: usize,
+}
+
+impl Iterator for Positions {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let pos = Position::new(self.next / self.cols, self.next % self.cols);
+        self.next += 1;
+        Some(pos)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+        (len, Some(len))
+    }
+}
+
+/// Cells of a grid along with their position, row after row.
+pub type Iter<'a, T> = std::iter::Zip<Positions, std::slice::Iter<'a, T>>;
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+/// Renders one line per row, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for value in self.row(row).unwrap_or_default() {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Text that cannot be parsed into a grid, located by its byte span in the
+/// text and by its 1-based line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridError {
+    pub offset: usize,
+    pub len: usize,
+    pub line: usize,
+    pub kind: GridErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridErrorKind {
+    Empty,
+    Ragged { expected: usize, found: usize },
+    InvalidCell(String),
+}
+
+impl Display for GridErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridErrorKind::Empty => write!(f, "empty grid"),
+            GridErrorKind::Ragged { expected, found } => {
+                write!(f, "expected {expected} columns, found {found}")
+            }
+            GridErrorKind::InvalidCell(value) => write!(f, "invalid cell `{value}`"),
+        }
+    }
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on line {}", self.kind, self.line)
+    }
+}
+
+impl Error for GridError {}
+
+#[cfg(test)]
+mod validation {
+    use crate::{Grid, GridErrorKind, Position};
+    use std::str::FromStr;
+
+    fn sample() -> Grid<char> {
+        Grid::from_str(
+            r#"
+        abc
+        def
+        ghi"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn tst_parse() {
+        let grid = sample();
+
+        assert_eq!((grid.rows(), grid.cols()), (3, 3));
+        assert_eq!(grid[Position::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef\nghi");
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.row(1), Some(&[3, 4][..]));
+    }
+
+    #[test]
+    fn tst_parse_errors() {
+        let err = Grid::from_str("ab\nc").unwrap_err();
+        assert_eq!((err.offset, err.len, err.line), (3, 1, 2));
+        assert_eq!(
+            err.kind,
+            GridErrorKind::Ragged {
+                expected: 2,
+                found: 1
+            }
+        );
+
+        let err = Grid::parse_with("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (err.offset, err.kind),
+            (4, GridErrorKind::InvalidCell("x".to_string()))
+        );
+
+        assert_eq!(
+            Grid::from_str("  \n").unwrap_err().kind,
+            GridErrorKind::Empty
+        );
+    }
+
+    #[test]
+    fn tst_access() {
+        let grid = sample();
+        let center = Position::new(1, 1);
+
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.get_offset(center, -1, 1), Some(&'c'));
+        assert_eq!(grid.get_offset(Position::new(0, 0), -1, 0), None);
+        assert_eq!(grid.get_offset(Position::new(2, 2), 0, 1), None);
+    }
+
+    #[test]
+    fn tst_views() {
+        let grid = sample();
+
+        assert_eq!(grid.row(0), Some(&['a', 'b', 'c'][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.column(1).collect::<String>(), "beh");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.diagonal(Position::new(0, 0)).collect::<String>(),
+            "aei"
+        );
+        assert_eq!(
+            grid.anti_diagonal(Position::new(0, 2)).collect::<String>(),
+            "ceg"
+        );
+    }
+
+    #[test]
+    fn tst_neighbors() {
+        let grid = sample();
+        let values =
+            |positions: Vec<Position>| positions.iter().map(|pos| grid[*pos]).collect::<String>();
+
+        assert_eq!(
+            values(grid.neighbors4(Position::new(1, 1)).collect()),
+            "bfhd"
+        );
+        assert_eq!(
+            values(grid.neighbors8(Position::new(1, 1)).collect()),
+            "bcfihgda"
+        );
+        assert_eq!(
+            values(grid.neighbors8(Position::new(0, 0)).collect()),
+            "bed"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{Grid, GridError, GridErrorKind};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// What a cell of the text is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segmentation {
+    /// One `char`, that is one Unicode scalar value.
+    Chars,
+    /// One extended grapheme cluster, such as a letter and its combining accents.
+    Graphemes,
+}
+
+/// What to do with rows that do not have as many cells as the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ragged<T> {
+    /// Fail on the first row whose width differs from the first one.
+    Reject,
+    /// Extend every row to the width of the longest one with the given cell.
+    Pad(T),
+}
+
+/// Cells of one line of the text.
+struct Row<T> {
+    offset: usize,
+    len: usize,
+    line: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per non-empty line, mapping each character to a cell.
+    ///
+    /// Surrounding whitespace of the text and of every line is ignored, and
+    /// `cell` rejects a character by returning `None`. Rows must all have
+    /// the same width.
+    pub fn parse_with(s: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let rows = parse_rows(s, Segmentation::Chars, |text| cell(text.chars().next()?))?;
+        Grid::from_rows(rows)
+    }
+
+    /// Parses one row per non-empty line, mapping each segment of the line
+    /// to a cell, with rows of different widths handled according to `ragged`.
+    pub fn parse_segments(
+        s: &str,
+        segmentation: Segmentation,
+        ragged: Ragged<T>,
+        cell: impl FnMut(&str) -> Option<T>,
+    ) -> Result<Self, GridError>
+    where
+        T: Clone,
+    {
+        let mut rows = parse_rows(s, segmentation, cell)?;
+
+        if let Ragged::Pad(fill) = ragged {
+            let cols = rows.iter().map(|row| row.cells.len()).max().unwrap_or(0);
+            for row in &mut rows {
+                row.cells.resize(cols, fill.clone());
+            }
+        }
+
+        Grid::from_rows(rows)
+    }
+
+    fn from_rows(rows: Vec<Row<T>>) -> Result<Self, GridError> {
+        let cols = match rows.first() {
+            Some(row) if !row.cells.is_empty() => row.cells.len(),
+            _ => {
+                return Err(GridError {
+                    offset: 0,
+                    len: 0,
+                    line: 1,
+                    kind: GridErrorKind::Empty,
+                })
+            }
+        };
+
+        if let Some(row) = rows.iter().find(|row| row.cells.len() != cols) {
+            return Err(GridError {
+                offset: row.offset,
+                len: row.len,
+                line: row.line,
+                kind: GridErrorKind::Ragged {
+                    expected: cols,
+                    found: row.cells.len(),
+                },
+            });
+        }
+
+        Ok(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flat_map(|row| row.cells).collect(),
+        })
+    }
+}
+
+fn parse_rows<T>(
+    s: &str,
+    segmentation: Segmentation,
+    mut cell: impl FnMut(&str) -> Option<T>,
+) -> Result<Vec<Row<T>>, GridError> {
+    let mut rows = vec![];
+
+    for line in s.trim().lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        let offset = line.as_ptr() as usize - s.as_ptr() as usize;
+        let number = s[..offset].matches('\n').count() + 1;
+
+        let segments: Box<dyn Iterator<Item = (usize, &str)>> = match segmentation {
+            Segmentation::Chars => Box::new(
+                line.char_indices()
+                    .map(|(pos, c)| (pos, &line[pos..pos + c.len_utf8()])),
+            ),
+            Segmentation::Graphemes => Box::new(line.grapheme_indices(true)),
+        };
+
+        let mut cells = vec![];
+        for (pos, text) in segments {
+            match cell(text) {
+                Some(value) => cells.push(value),
+                None => {
+                    return Err(GridError {
+                        offset: offset + pos,
+                        len: text.len(),
+                        line: number,
+                        kind: GridErrorKind::InvalidCell(text.to_string()),
+                    })
+                }
+            }
+        }
+
+        rows.push(Row {
+            offset,
+            len: line.len(),
+            line: number,
+            cells,
+        });
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::{Grid, GridErrorKind, Position, Ragged, Segmentation};
+
+    #[test]
+    fn tst_parse_unicode() {
+        let grid = Grid::parse_with("ÄÖ\nœ→", Some).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 2));
+        assert_eq!(grid[Position::new(1, 0)], 'œ');
+
+        // `e` followed by a combining acute accent is one letter on screen
+        let text = "e\u{301}x\nab";
+        let err = Grid::parse_with(text, Some).unwrap_err();
+        assert_eq!((err.line, err.offset), (2, 5));
+
+        let grid = Grid::parse_segments(text, Segmentation::Graphemes, Ragged::Reject, |g| {
+            Some(g.to_string())
+        })
+        .unwrap();
+        assert_eq!(grid[Position::new(0, 0)], "e\u{301}");
+        assert_eq!(grid.cols(), 2);
+
+        let err = Grid::parse_segments(text, Segmentation::Graphemes, Ragged::Reject, |g| {
+            let mut chars = g.chars();
+            chars.next().filter(|_| chars.next().is_none())
+        })
+        .unwrap_err();
+        assert_eq!(
+            (err.line, err.offset, err.len, err.kind),
+            (1, 0, 3, GridErrorKind::InvalidCell("e\u{301}".to_string()))
+        );
+    }
+
+    #[test]
+    fn tst_parse_ragged() {
+        let text = "abc\nd\nefgh\nij";
+
+        let err = Grid::parse_segments(text, Segmentation::Chars, Ragged::Reject, |c| {
+            c.chars().next()
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.offset, err.len), (2, 4, 1));
+        assert_eq!(err.to_string(), "expected 3 columns, found 1 on line 2");
+
+        let grid = Grid::parse_segments(text, Segmentation::Chars, Ragged::Pad('.'), |c| {
+            c.chars().next()
+        })
+        .unwrap();
+        assert_eq!(grid.to_string(), "abc.\nd...\nefgh\nij..");
+
+        // Blank lines are not rows, but still count as lines
+        let text = "abc\n\n  \nabc\nd";
+        let err = Grid::parse_segments(text, Segmentation::Chars, Ragged::Reject, |c| {
+            c.chars().next()
+        })
+        .unwrap_err();
+        assert_eq!(err.line, 5);
+
+        let grid = Grid::parse_segments(text, Segmentation::Chars, Ragged::Pad('.'), |c| {
+            c.chars().next()
+        })
+        .unwrap();
+        assert_eq!(grid.to_string(), "abc\nabc\nd..");
+    }
+}
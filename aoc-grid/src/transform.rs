@@ -0,0 +1,207 @@
+use crate::{Grid, Position};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, Range};
+
+impl<T: Clone> Grid<T> {
+    /// Grid of `rows` by `cols` whose cell at `pos` is the cell of `self` at `source(pos)`.
+    fn remap(&self, rows: usize, cols: usize, source: impl Fn(Position) -> Position) -> Grid<T> {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| Position::new(row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Grid::from_cells(rows, cols, cells)
+    }
+
+    /// Quarter turn clockwise: the first column, read upwards, becomes the first row.
+    pub fn rotate90(&self) -> Grid<T> {
+        let rows = self.rows;
+        self.remap(self.cols, self.rows, |pos| {
+            Position::new(rows - 1 - pos.col, pos.row)
+        })
+    }
+
+    /// Mirror image along the main diagonal: rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.cols, self.rows, |pos| Position::new(pos.col, pos.row))
+    }
+
+    /// Mirror image, left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let cols = self.cols;
+        self.remap(self.rows, self.cols, |pos| {
+            Position::new(pos.row, cols - 1 - pos.col)
+        })
+    }
+
+    /// Mirror image, top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let rows = self.rows;
+        self.remap(self.rows, self.cols, |pos| {
+            Position::new(rows - 1 - pos.row, pos.col)
+        })
+    }
+
+    /// The 8 symmetries of the grid: its 4 quarter turns, then the 4 quarter
+    /// turns of its left to right mirror image.
+    pub fn symmetries(&self) -> Vec<Grid<T>> {
+        let mut grids = Vec::with_capacity(8);
+
+        for mut grid in [self.clone(), self.flip_horizontal()] {
+            for _ in 0..4 {
+                let next = grid.rotate90();
+                grids.push(grid);
+                grid = next;
+            }
+        }
+
+        grids
+    }
+}
+
+impl<T> Grid<T> {
+    /// View of the cells in `rows` and `cols`, `None` when they go past the grid.
+    pub fn subgrid(&self, rows: Range<usize>, cols: Range<usize>) -> Option<SubGrid<'_, T>> {
+        if rows.start > rows.end || cols.start > cols.end {
+            return None;
+        }
+        if rows.end > self.rows || cols.end > self.cols {
+            return None;
+        }
+
+        Some(SubGrid {
+            grid: self,
+            rows,
+            cols,
+        })
+    }
+}
+
+/// Rectangle of a grid, borrowed without copying its cells.
+///
+/// Positions are relative to the top left corner of the rectangle.
+#[derive(Debug, Clone)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols.len()
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.rows() && pos.col < self.cols()
+    }
+
+    /// Position in the underlying grid.
+    pub fn origin(&self, pos: Position) -> Position {
+        Position::new(self.rows.start + pos.row, self.cols.start + pos.col)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&'a T> {
+        let grid = self.grid;
+        self.contains(pos).then(|| &grid[self.origin(pos)])
+    }
+
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        let grid = self.grid;
+        let cells = grid.row(self.rows.start.checked_add(row)?)?;
+        (row < self.rows()).then(|| &cells[self.cols.clone()])
+    }
+
+    /// Cells and their positions, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &'a T)> + '_ {
+        (0..self.rows()).flat_map(move |row| {
+            self.row(row)
+                .unwrap_or_default()
+                .iter()
+                .enumerate()
+                .map(move |(col, value)| (Position::new(row, col), value))
+        })
+    }
+
+    /// Copy of the viewed cells.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.iter().map(|(_, value)| value.clone()).collect();
+        Grid::from_cells(self.rows(), self.cols(), cells)
+    }
+}
+
+impl<T> Index<Position> for SubGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} out of a {}x{} subgrid", self.rows(), self.cols()))
+    }
+}
+
+/// Renders one line per row, like the grid itself.
+impl<T: Display> Display for SubGrid<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.rows() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for value in self.row(row).unwrap_or_default() {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::{Grid, Position};
+    use std::str::FromStr;
+
+    fn sample() -> Grid<char> {
+        Grid::from_str("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn tst_transform() {
+        let grid = sample();
+
+        assert_eq!(grid.rotate90().to_string(), "da\neb\nfc");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate90().rotate90().rotate90().rotate90(), grid);
+        assert_eq!(grid.transpose(), grid.rotate90().flip_horizontal());
+
+        let symmetries = grid.symmetries();
+        assert_eq!(symmetries.len(), 8);
+        assert_eq!(symmetries[0], grid);
+        assert!(symmetries.contains(&grid.transpose()));
+        assert!(symmetries.contains(&grid.flip_vertical()));
+    }
+
+    #[test]
+    fn tst_subgrid() {
+        let grid = Grid::from_str("abcd\nefgh\nijkl").unwrap();
+
+        let view = grid.subgrid(1..3, 1..3).unwrap();
+        assert_eq!((view.rows(), view.cols()), (2, 2));
+        assert_eq!(view.to_string(), "fg\njk");
+        assert_eq!(view[Position::new(1, 0)], 'j');
+        assert_eq!(view.get(Position::new(0, 2)), None);
+        assert_eq!(view.origin(Position::new(1, 1)), Position::new(2, 2));
+        assert_eq!(view.row(0), Some(&['f', 'g'][..]));
+        assert_eq!(view.to_grid().rotate90().to_string(), "jf\nkg");
+
+        assert!(grid.subgrid(0..4, 0..1).is_none());
+        assert_eq!(grid.subgrid(3..3, 0..4).unwrap().iter().count(), 0);
+    }
+}
@@ -0,0 +1,278 @@
+use aoc_core::{Solution, SolveError};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Summary of the timings of one phase over every iteration.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn of(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let at = |ratio: f64| samples[((samples.len() - 1) as f64 * ratio).round() as usize];
+
+        Stats {
+            min: samples[0],
+            median: at(0.5),
+            p95: at(0.95),
+        }
+    }
+}
+
+/// Part of a solution that is timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl Phase {
+    pub fn name(&self) -> String {
+        match self {
+            Phase::Parse => "parse".to_string(),
+            Phase::Part(part) => format!("part{part}"),
+        }
+    }
+}
+
+/// Timings of the parsing and of the requested parts of a day.
+pub struct Report {
+    pub iterations: usize,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+/// One line of a report, as emitted in JSON and stored in baselines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: String,
+    pub iterations: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<Comparison>,
+}
+
+impl Measurement {
+    /// Spread between the median and the 95th percentile, relative to the median.
+    fn noise(&self) -> f64 {
+        self.p95_ns.saturating_sub(self.median_ns) as f64 / self.median_ns.max(1) as f64
+    }
+
+    /// Compares the median against the one of `baseline`.
+    ///
+    /// A change is only significant when it exceeds both the tolerance and
+    /// the noise observed in either run, so a jittery phase does not fail on
+    /// a single unlucky sample.
+    pub fn compare(&self, baseline: &Measurement, tolerance: f64) -> Comparison {
+        let change = self.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0;
+        let noise = self.noise().max(baseline.noise());
+
+        let status = if change.abs() <= tolerance {
+            Status::Unchanged
+        } else if change.abs() <= noise {
+            Status::Noisy
+        } else if change > 0.0 {
+            Status::Slower
+        } else {
+            Status::Faster
+        };
+
+        Comparison { change, status }
+    }
+}
+
+/// Change of a measurement since the baseline.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Comparison {
+    /// Relative change of the median, `0.1` meaning 10% slower.
+    pub change: f64,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Faster,
+    Unchanged,
+    /// Beyond the tolerance, but within the noise of the measurements.
+    Noisy,
+    Slower,
+}
+
+/// Options to save or compare benchmark baselines.
+#[derive(Args)]
+pub struct BaselineArgs {
+    /// Save the measurements as the new baseline in this directory
+    #[arg(long, value_name = "DIR")]
+    pub save_baseline: Option<PathBuf>,
+
+    /// Compare the measurements with the baseline saved in this directory
+    #[arg(long, value_name = "DIR")]
+    pub baseline: Option<PathBuf>,
+
+    /// Slowdown of the median, in percent, tolerated before failing
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    pub tolerance: f64,
+}
+
+/// Baselines are stored as one file per day, holding one entry per phase.
+fn baseline_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day}.json"))
+}
+
+/// Measurements saved for `day`, none when the day has no baseline yet.
+pub fn load_baseline(dir: &Path, day: u8) -> Result<Vec<Measurement>, String> {
+    let path = baseline_path(dir, day);
+    let json = match std::fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(format!("cannot read baseline {}: {err}", path.display())),
+    };
+
+    serde_json::from_str(&json).map_err(|err| format!("invalid baseline {}: {err}", path.display()))
+}
+
+pub fn save_baseline(dir: &Path, day: u8, measurements: &[Measurement]) -> Result<(), String> {
+    let path = baseline_path(dir, day);
+    let measurements = measurements
+        .iter()
+        .map(|measurement| Measurement {
+            comparison: None,
+            ..measurement.clone()
+        })
+        .collect::<Vec<_>>();
+    let json = serde_json::to_string_pretty(&measurements).expect("Can serialize measurements");
+
+    std::fs::create_dir_all(dir)
+        .and_then(|_| std::fs::write(&path, json))
+        .map_err(|err| format!("cannot write baseline {}: {err}", path.display()))
+}
+
+impl Report {
+    pub fn measurements(&self, day: u8) -> Vec<Measurement> {
+        self.phases
+            .iter()
+            .map(|(phase, stats)| Measurement {
+                day,
+                phase: phase.name(),
+                iterations: self.iterations,
+                min_ns: stats.min.as_nanos(),
+                median_ns: stats.median.as_nanos(),
+                p95_ns: stats.p95.as_nanos(),
+                comparison: None,
+            })
+            .collect()
+    }
+}
+
+/// Times `iterations` runs of the parsing and of each of `parts`.
+///
+/// The input is parsed and each part solved once beforehand, which both
+/// validates them and warms up the caches before anything is measured.
+pub fn measure<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    iterations: usize,
+) -> Result<Report, SolveError> {
+    let iterations = iterations.max(1);
+    let parsed = S::parse(input)?;
+    let mut phases = vec![(Phase::Parse, sample(iterations, || S::parse(input)))];
+
+    for part in parts {
+        match part {
+            1 => S::part1(&parsed)?,
+            _ => S::part2(&parsed)?,
+        };
+        let stats = match part {
+            1 => sample(iterations, || S::part1(&parsed)),
+            _ => sample(iterations, || S::part2(&parsed)),
+        };
+        phases.push((Phase::Part(*part), stats));
+    }
+
+    Ok(Report { iterations, phases })
+}
+
+fn sample<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::of(samples)
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::bench::{load_baseline, save_baseline, Measurement, Stats, Status};
+    use std::time::Duration;
+
+    fn measurement(median_ns: u128, p95_ns: u128) -> Measurement {
+        Measurement {
+            day: 4,
+            phase: "part1".to_string(),
+            iterations: 100,
+            min_ns: median_ns,
+            median_ns,
+            p95_ns,
+            comparison: None,
+        }
+    }
+
+    #[test]
+    fn tst_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::of(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+
+    #[test]
+    fn tst_compare() {
+        let baseline = measurement(1000, 1050);
+
+        let status = |median, p95| measurement(median, p95).compare(&baseline, 0.1).status;
+        assert_eq!(status(1080, 1100), Status::Unchanged);
+        assert_eq!(status(1300, 1350), Status::Slower);
+        assert_eq!(status(1300, 2000), Status::Noisy);
+        assert_eq!(status(500, 520), Status::Faster);
+
+        // A hand-edited p95 below the median is not noise
+        assert_eq!(measurement(1000, 900).noise(), 0.0);
+    }
+
+    #[test]
+    fn tst_baseline() {
+        let dir = std::env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // Days without a baseline yet have nothing to compare with
+        assert!(load_baseline(&dir, 4).unwrap().is_empty());
+
+        save_baseline(&dir, 4, &[measurement(1000, 1050)]).unwrap();
+        let saved = load_baseline(&dir, 4).unwrap();
+        assert_eq!((saved.len(), saved[0].median_ns), (1, 1000));
+
+        std::fs::write(dir.join("day5.json"), "[{").unwrap();
+        assert!(load_baseline(&dir, 5)
+            .unwrap_err()
+            .starts_with("invalid baseline"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
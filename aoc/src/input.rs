@@ -0,0 +1,190 @@
+use clap::Args;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Source checkout of the workspace, searched last.
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Options selecting where puzzle inputs are read from.
+#[derive(Args)]
+pub struct InputArgs {
+    /// Read the input from this file, or from stdin with `-`
+    #[arg(long, value_name = "FILE")]
+    pub input: Option<PathBuf>,
+
+    /// Directory holding one `dayN/full.txt` folder per day
+    #[arg(long, value_name = "DIR", env = "AOC_INPUT_DIR")]
+    pub input_dir: Option<PathBuf>,
+}
+
+/// Puzzle input along with where it was read from.
+pub struct Input {
+    pub path: Option<PathBuf>,
+    pub text: String,
+}
+
+impl Input {
+    /// Human readable origin of the input.
+    pub fn origin(&self) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => "<stdin>".to_string(),
+        }
+    }
+}
+
+pub enum InputError {
+    Unreadable(String, std::io::Error),
+    NotFound(Vec<PathBuf>),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Unreadable(origin, err) => write!(f, "cannot read {origin}: {err}"),
+            InputError::NotFound(searched) => {
+                write!(f, "no input found, searched:")?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                write!(f, "\nuse --input <FILE> or set AOC_INPUT_DIR")
+            }
+        }
+    }
+}
+
+impl InputArgs {
+    /// Whether the options can be used for every day at once, which `--input`
+    /// cannot as it holds a single day.
+    pub fn check(&self, all: bool) -> Result<(), String> {
+        match (&self.input, all) {
+            (Some(_), true) => {
+                Err("--input can only be used when running a single day".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Locations of a day's full input, in the order they are tried.
+    pub fn candidates(&self, name: &str) -> Vec<PathBuf> {
+        let mut dirs = vec![];
+        if let Some(dir) = &self.input_dir {
+            dirs.push(dir.clone());
+        }
+        dirs.push(PathBuf::from("inputs"));
+        dirs.push(Path::new(WORKSPACE).join("inputs"));
+
+        dirs.into_iter()
+            .map(|dir| dir.join(name).join("full.txt"))
+            .collect()
+    }
+
+    pub fn read(&self, name: &str) -> Result<Input, InputError> {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|err| InputError::Unreadable("<stdin>".to_string(), err))?;
+
+                Ok(Input { path: None, text })
+            }
+            Some(path) => read_file(path),
+            None => {
+                let searched = self.candidates(name);
+                match searched.iter().find(|path| path.is_file()) {
+                    Some(path) => read_file(path),
+                    None => Err(InputError::NotFound(searched)),
+                }
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<Input, InputError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(Input {
+            path: Some(path.to_path_buf()),
+            text,
+        }),
+        Err(err) => Err(InputError::Unreadable(path.display().to_string(), err)),
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::input::{InputArgs, InputError, WORKSPACE};
+    use std::path::{Path, PathBuf};
+
+    fn args(input: Option<&str>, input_dir: Option<&Path>) -> InputArgs {
+        InputArgs {
+            input: input.map(PathBuf::from),
+            input_dir: input_dir.map(Path::to_path_buf),
+        }
+    }
+
+    #[test]
+    fn tst_candidates() {
+        let workspace = Path::new(WORKSPACE).join("inputs/day3/full.txt");
+
+        assert_eq!(
+            args(None, Some(Path::new("/data"))).candidates("day3"),
+            vec![
+                PathBuf::from("/data/day3/full.txt"),
+                PathBuf::from("inputs/day3/full.txt"),
+                workspace.clone(),
+            ]
+        );
+        assert_eq!(
+            args(None, None).candidates("day3"),
+            vec![PathBuf::from("inputs/day3/full.txt"), workspace]
+        );
+    }
+
+    #[test]
+    fn tst_read() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("day42")).unwrap();
+        std::fs::write(dir.join("day42/full.txt"), "42\n").unwrap();
+
+        let inputs = args(None, Some(&dir));
+        let input = inputs.read("day42").ok().unwrap();
+        assert_eq!(input.path, Some(dir.join("day42/full.txt")));
+        assert_eq!(input.text, "42\n");
+
+        // Every location searched is listed
+        let searched = inputs.candidates("day43");
+        match inputs.read("day43") {
+            Err(err @ InputError::NotFound(_)) => {
+                let message = err.to_string();
+                assert!(message.starts_with("no input found, searched:"));
+                for path in &searched {
+                    assert!(message.contains(&format!("\n  {}\n", path.display())));
+                }
+            }
+            _ => panic!("day43 has no input"),
+        }
+
+        let missing = dir.join("missing.txt");
+        let inputs = args(missing.to_str(), None);
+        assert!(matches!(
+            inputs.read("day42"),
+            Err(InputError::Unreadable(origin, _)) if origin == missing.display().to_string()
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn tst_check() {
+        let single = args(Some("day3.txt"), None);
+        assert!(single.check(false).is_ok());
+        assert_eq!(
+            single.check(true),
+            Err("--input can only be used when running a single day".to_string())
+        );
+        assert!(args(None, Some(Path::new("/data"))).check(true).is_ok());
+    }
+}
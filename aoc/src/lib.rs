@@ -0,0 +1,6 @@
+pub mod bench;
+pub mod input;
+pub mod output;
+pub mod registry;
+pub mod scaffold;
+pub mod verify;
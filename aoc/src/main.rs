@@ -0,0 +1,368 @@
+use aoc::bench::{self, BaselineArgs, Comparison, Status};
+use aoc::input::{Input, InputArgs};
+use aoc::output::{self, Format, Outcome};
+use aoc::registry::{self, Day};
+use aoc::scaffold;
+use aoc::verify::{self, Verdict};
+use aoc_core::SolveError;
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day, or of every day
+    Run {
+        /// Day number, or `all`
+        day: Selection,
+
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Compare answers with the known-good ones stored next to the input
+        #[arg(long)]
+        verify: bool,
+
+        /// How results are printed
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        #[command(flatten)]
+        inputs: InputArgs,
+    },
+    /// Time the parsing and each part of a single day, or of every day
+    Bench {
+        /// Day number, or `all`
+        day: Selection,
+
+        /// Only time the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Number of timed runs of each phase
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+
+        /// Print the measurements as JSON
+        #[arg(long)]
+        json: bool,
+
+        #[command(flatten)]
+        baseline: BaselineArgs,
+
+        #[command(flatten)]
+        inputs: InputArgs,
+    },
+    /// Create the crate and inputs of a new day, registered with the runner
+    New {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Title of the puzzle
+        #[arg(long, default_value = "")]
+        title: String,
+
+        /// Root of the workspace to create the day in
+        #[arg(long, value_name = "DIR", default_value = scaffold::WORKSPACE)]
+        root: PathBuf,
+    },
+}
+
+#[derive(Clone)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        match s.parse::<u8>() {
+            Ok(number) if registry::find(number).is_some() => Ok(Selection::Day(number)),
+            _ => Err(format!("no solution registered for day `{s}`")),
+        }
+    }
+}
+
+impl Selection {
+    fn days(&self) -> Vec<&'static Day> {
+        match self {
+            Selection::All => registry::DAYS.iter().collect(),
+            Selection::Day(number) => registry::find(*number).into_iter().collect(),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            verify,
+            format,
+            inputs,
+        } => run(&day, part, verify, format, &inputs),
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            json,
+            baseline,
+            inputs,
+        } => bench(&day, part, iterations, json, &baseline, &inputs),
+        Command::New { day, title, root } => new(day, &title, &root),
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn check_inputs(selection: &Selection, inputs: &InputArgs) -> bool {
+    match inputs.check(matches!(selection, Selection::All)) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("error: {err}");
+            false
+        }
+    }
+}
+
+fn read_input(day: &Day, inputs: &InputArgs) -> Option<Input> {
+    match inputs.read(&day.name()) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!("error: {}: {err}", day.name());
+            None
+        }
+    }
+}
+
+fn report_error(err: &SolveError, input: &Input) {
+    match err {
+        SolveError::Parse(err) => eprintln!("error: invalid input {}\n  {err}", input.origin()),
+        SolveError::Answer(err) => eprintln!("error: no answer for {}\n  {err}", input.origin()),
+    }
+}
+
+fn run(
+    selection: &Selection,
+    part: Option<u8>,
+    verify: bool,
+    format: Format,
+    inputs: &InputArgs,
+) -> ExitCode {
+    if !check_inputs(selection, inputs) {
+        return ExitCode::FAILURE;
+    }
+
+    let parts = parts(part);
+    let mut outcomes = vec![];
+    let mut failed = false;
+
+    'days: for day in selection.days() {
+        let Some(input) = read_input(day, inputs) else {
+            failed = true;
+            continue;
+        };
+
+        for (i, part) in parts.iter().enumerate() {
+            let start = Instant::now();
+            let answer = day.part(*part)(input.text.trim());
+            let time = start.elapsed();
+            let outcome = |part: u8, answer, verdict| Outcome {
+                day: day.number,
+                title: day.title,
+                part,
+                answer,
+                time,
+                verdict,
+            };
+
+            match answer {
+                Ok(answer) => {
+                    let verdict = verify.then(|| verify::verify(&input, *part, &answer));
+                    outcomes.push(outcome(*part, Ok(answer), verdict));
+                }
+                Err(err) => {
+                    report_error(&err, &input);
+                    failed = true;
+                    match err {
+                        SolveError::Answer(_) => {
+                            outcomes.push(outcome(*part, Err(err.to_string()), None));
+                        }
+                        // The input is the same for every part, none of the remaining ones can run
+                        SolveError::Parse(_) => {
+                            let remaining = parts[i..].iter();
+                            outcomes.extend(
+                                remaining.map(|part| outcome(*part, Err(err.to_string()), None)),
+                            );
+                            continue 'days;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    print!("{}", output::render(format, &outcomes));
+
+    let regressions = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome.verdict, Some(Verdict::Fail { .. })))
+        .count();
+    if regressions > 0 {
+        eprintln!("error: {regressions} answer(s) differ from the known-good ones");
+        failed = true;
+    }
+
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+fn bench(
+    selection: &Selection,
+    part: Option<u8>,
+    iterations: usize,
+    json: bool,
+    baseline: &BaselineArgs,
+    inputs: &InputArgs,
+) -> ExitCode {
+    if !check_inputs(selection, inputs) {
+        return ExitCode::FAILURE;
+    }
+
+    let parts = parts(part);
+    let tolerance = baseline.tolerance / 100.0;
+    let mut all_measurements = vec![];
+    let mut slowdowns = 0;
+    let mut failed = false;
+
+    for day in selection.days() {
+        let name = day.name();
+        let Some(input) = read_input(day, inputs) else {
+            failed = true;
+            continue;
+        };
+
+        let report = match (day.bench)(input.text.trim(), &parts, iterations) {
+            Ok(report) => report,
+            Err(err) => {
+                report_error(&err, &input);
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut measurements = report.measurements(day.number);
+
+        if let Some(dir) = &baseline.baseline {
+            let saved = match bench::load_baseline(dir, day.number) {
+                Ok(saved) => saved,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            for measurement in &mut measurements {
+                let previous = saved.iter().find(|m| m.phase == measurement.phase);
+                measurement.comparison = previous.map(|m| measurement.compare(m, tolerance));
+            }
+        }
+
+        if let Some(dir) = &baseline.save_baseline {
+            if let Err(err) = bench::save_baseline(dir, day.number, &measurements) {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+
+        slowdowns += measurements
+            .iter()
+            .filter_map(|m| m.comparison)
+            .filter(|comparison| comparison.status == Status::Slower)
+            .count();
+
+        if json {
+            all_measurements.extend(measurements);
+            continue;
+        }
+
+        println!("--- Day {}: {} ---", day.number, day.title);
+        for measurement in &measurements {
+            let comparison = match measurement.comparison {
+                Some(Comparison { change, status }) => {
+                    format!("  {:+6.1}% {status:?}", change * 100.0)
+                }
+                None if baseline.baseline.is_some() => "  no baseline".to_string(),
+                None => String::new(),
+            };
+            println!(
+                "{name} {:<6} min {:>10.1?}  median {:>10.1?}  p95 {:>10.1?}{comparison}",
+                measurement.phase,
+                Duration::from_nanos(measurement.min_ns as u64),
+                Duration::from_nanos(measurement.median_ns as u64),
+                Duration::from_nanos(measurement.p95_ns as u64),
+            );
+        }
+    }
+
+    if json {
+        let json =
+            serde_json::to_string_pretty(&all_measurements).expect("Can serialize measurements");
+        println!("{json}");
+    }
+
+    if slowdowns > 0 {
+        eprintln!(
+            "error: {slowdowns} phase(s) slower than the baseline beyond {}%",
+            baseline.tolerance
+        );
+        failed = true;
+    }
+
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+fn new(day: u8, title: &str, root: &Path) -> ExitCode {
+    match scaffold::new_day(root, day, title) {
+        Ok(changed) => {
+            for path in changed {
+                println!("wrote {}", path.display());
+            }
+            println!("implement day{day}/src/lib.rs, then `aoc run {day}`");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
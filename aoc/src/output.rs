@@ -0,0 +1,249 @@
+use crate::verify::Verdict;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Tap,
+}
+
+/// Answer of one part of a day.
+pub struct Outcome {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    /// Why the input could not be parsed or the part solved, on error.
+    pub answer: Result<String, String>,
+    pub time: Duration,
+    /// Only set when the run verifies answers.
+    pub verdict: Option<Verdict>,
+}
+
+impl Outcome {
+    fn status(&self) -> Option<&'static str> {
+        if self.answer.is_err() {
+            return Some("error");
+        }
+
+        self.verdict.as_ref().map(|verdict| match verdict {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.answer.as_ref().err().map(String::as_str)
+    }
+
+    fn expected(&self) -> Option<&str> {
+        match &self.verdict {
+            Some(Verdict::Fail { expected }) => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    time_ns: u128,
+    status: Option<&'a str>,
+    expected: Option<&'a str>,
+    error: Option<&'a str>,
+}
+
+impl<'a> From<&'a Outcome> for Record<'a> {
+    fn from(outcome: &'a Outcome) -> Self {
+        Record {
+            day: outcome.day,
+            part: outcome.part,
+            answer: outcome.answer.as_deref().ok(),
+            time_ns: outcome.time.as_nanos(),
+            status: outcome.status(),
+            expected: outcome.expected(),
+            error: outcome.error(),
+        }
+    }
+}
+
+pub fn render(format: Format, outcomes: &[Outcome]) -> String {
+    match format {
+        Format::Text => render_text(outcomes),
+        Format::Json => {
+            let records = outcomes.iter().map(Record::from).collect::<Vec<_>>();
+            serde_json::to_string_pretty(&records).expect("Can serialize outcomes") + "\n"
+        }
+        Format::Csv => render_csv(outcomes),
+        Format::Tap => render_tap(outcomes),
+    }
+}
+
+fn render_text(outcomes: &[Outcome]) -> String {
+    let mut out = String::new();
+
+    for (i, outcome) in outcomes.iter().enumerate() {
+        if i == 0 || outcomes[i - 1].day != outcome.day {
+            writeln!(out, "--- Day {}: {} ---", outcome.day, outcome.title).unwrap();
+        }
+
+        write!(out, "day{} part {}: ", outcome.day, outcome.part).unwrap();
+        match (&outcome.answer, &outcome.verdict) {
+            (Err(_), _) => writeln!(out, "ERROR").unwrap(),
+            (Ok(answer), Some(verdict)) => writeln!(out, "{answer} {verdict}").unwrap(),
+            (Ok(answer), None) => writeln!(out, "{answer}").unwrap(),
+        }
+    }
+
+    out
+}
+
+fn render_csv(outcomes: &[Outcome]) -> String {
+    let mut out = String::from("day,part,answer,time_ns,status,expected,error\n");
+
+    for outcome in outcomes {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            outcome.day,
+            outcome.part,
+            csv_field(outcome.answer.as_deref().unwrap_or_default()),
+            outcome.time.as_nanos(),
+            outcome.status().unwrap_or_default(),
+            csv_field(outcome.expected().unwrap_or_default()),
+            csv_field(outcome.error().unwrap_or_default()),
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+/// Quotes a field when it would otherwise break the row.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn render_tap(outcomes: &[Outcome]) -> String {
+    let mut out = format!("TAP version 13\n1..{}\n", outcomes.len());
+
+    for (i, outcome) in outcomes.iter().enumerate() {
+        let ok = match (&outcome.answer, &outcome.verdict) {
+            (Err(_), _) | (_, Some(Verdict::Fail { .. })) => "not ok",
+            _ => "ok",
+        };
+        let directive = match (&outcome.answer, &outcome.verdict) {
+            (Err(err), _) => format!(" # error: {}", err.replace('\n', " ")),
+            (_, Some(Verdict::Unknown)) => " # SKIP no known-good answer".to_string(),
+            _ => String::new(),
+        };
+
+        writeln!(
+            out,
+            "{ok} {} - day{} part {}{directive}",
+            i + 1,
+            outcome.day,
+            outcome.part
+        )
+        .unwrap();
+        writeln!(out, "  ---").unwrap();
+        match &outcome.answer {
+            Ok(answer) => writeln!(out, "  answer: {answer:?}").unwrap(),
+            Err(err) => writeln!(out, "  error: {err:?}").unwrap(),
+        }
+        if let Some(expected) = outcome.expected() {
+            writeln!(out, "  expected: {expected:?}").unwrap();
+        }
+        writeln!(out, "  time_ns: {}", outcome.time.as_nanos()).unwrap();
+        writeln!(out, "  ...").unwrap();
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::output::{render, Format, Outcome};
+    use crate::verify::Verdict;
+    use std::time::Duration;
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                day: 3,
+                title: "Mull It Over",
+                part: 1,
+                answer: Ok("161".to_string()),
+                time: Duration::from_nanos(1200),
+                verdict: Some(Verdict::Pass),
+            },
+            Outcome {
+                day: 3,
+                title: "Mull It Over",
+                part: 2,
+                answer: Ok("1,2".to_string()),
+                time: Duration::from_nanos(800),
+                verdict: Some(Verdict::Fail {
+                    expected: "48".to_string(),
+                }),
+            },
+            Outcome {
+                day: 4,
+                title: "Ceres Search",
+                part: 1,
+                answer: Err("day4, line 2, column 1: ragged grid (`ab`)".to_string()),
+                time: Duration::from_nanos(300),
+                verdict: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn tst_render_csv() {
+        assert_eq!(
+            render(Format::Csv, &outcomes()),
+            "day,part,answer,time_ns,status,expected,error\n\
+             3,1,161,1200,pass,,\n\
+             3,2,\"1,2\",800,fail,48,\n\
+             4,1,,300,error,,\"day4, line 2, column 1: ragged grid (`ab`)\"\n"
+        );
+    }
+
+    #[test]
+    fn tst_render_tap() {
+        let tap = render(Format::Tap, &outcomes());
+
+        assert!(tap.starts_with("TAP version 13\n1..3\nok 1 - day3 part 1\n"));
+        assert!(tap.contains("not ok 2 - day3 part 2\n"));
+        assert!(tap.contains("  expected: \"48\"\n"));
+        assert!(tap.contains(
+            "not ok 3 - day4 part 1 # error: day4, line 2, column 1: ragged grid (`ab`)\n"
+        ));
+    }
+
+    #[test]
+    fn tst_render_errors() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &outcomes())).unwrap();
+        assert_eq!(json[0]["answer"], "161");
+        assert!(json[0]["error"].is_null());
+        assert!(json[2]["answer"].is_null());
+        assert_eq!(json[2]["status"], "error");
+        assert!(json[2]["error"].as_str().unwrap().contains("ragged grid"));
+
+        let text = render(Format::Text, &outcomes());
+        assert!(text.ends_with("--- Day 4: Ceres Search ---\nday4 part 1: ERROR\n"));
+    }
+}
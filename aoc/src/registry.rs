@@ -0,0 +1,54 @@
+use crate::bench::{self, Report};
+use aoc_core::{Solution, SolveError};
+
+/// Entry point of one part, from raw input to answer.
+pub type Solver = fn(&str) -> Result<String, SolveError>;
+
+/// Times the parsing and the given parts over a number of iterations.
+pub type Bencher = fn(&str, &[u8], usize) -> Result<Report, SolveError>;
+
+/// A registered day and the entry points of both of its parts.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub solve: Solver,
+    pub solve_complex: Solver,
+    pub bench: Bencher,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Day {
+        Day {
+            number: S::NUMBER,
+            title: S::TITLE,
+            solve: S::solve,
+            solve_complex: S::solve_complex,
+            bench: bench::measure::<S>,
+        }
+    }
+
+    /// Name of the day, which is also the name of its crate and inputs folder.
+    pub fn name(&self) -> String {
+        format!("day{}", self.number)
+    }
+
+    pub fn part(&self, part: u8) -> Solver {
+        match part {
+            1 => self.solve,
+            _ => self.solve_complex,
+        }
+    }
+}
+
+/// Every day the runner knows about, in order.
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
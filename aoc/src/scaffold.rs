@@ -0,0 +1,288 @@
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const CASES_TEMPLATE: &str = include_str!("../templates/cases.toml.tmpl");
+
+/// Rewrites the content of a file to account for a new day.
+type Edit = fn(&str, u8) -> Result<String, String>;
+
+/// Source checkout of the workspace, where new days are created by default.
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Creates the crate and inputs of a new day and registers it with the runner.
+///
+/// Returns the files that were created or modified. On error, every change is
+/// undone so that the same command can be run again.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{day}");
+    let crate_dir = root.join(&name);
+    let inputs_dir = root.join("inputs").join(&name);
+
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // The title ends up in a Rust string literal
+    let title = title.escape_debug().to_string();
+    let render = |template: &str| {
+        template
+            .replace("{{day}}", &day.to_string())
+            .replace("{{title}}", &title)
+    };
+
+    // Everything that can be checked is, before touching any file
+    let edits: [(PathBuf, Edit); 3] = [
+        (root.join("Cargo.toml"), add_member),
+        (root.join("aoc/Cargo.toml"), add_dependency),
+        (root.join("aoc/src/registry.rs"), register),
+    ];
+    let mut edited = vec![];
+    for (path, edit) in edits {
+        let original = std::fs::read_to_string(&path)
+            .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+        let content = edit(&original, day).map_err(|err| format!("{}: {err}", path.display()))?;
+        edited.push((path, original, content));
+    }
+
+    let mut changes = Changes::default();
+    let result = (|| {
+        changes.create(crate_dir.join("Cargo.toml"), render(CARGO_TEMPLATE))?;
+        changes.create(crate_dir.join("src/lib.rs"), render(LIB_TEMPLATE))?;
+        if !inputs_dir.join("sample.txt").exists() {
+            changes.create(inputs_dir.join("sample.txt"), String::new())?;
+        }
+        if !inputs_dir.join("cases.toml").exists() {
+            changes.create(inputs_dir.join("cases.toml"), render(CASES_TEMPLATE))?;
+        }
+        for (path, original, content) in edited {
+            changes.replace(path, original, content)?;
+        }
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => Ok(changes.changed),
+        Err(err) => {
+            changes.undo();
+            Err(err)
+        }
+    }
+}
+
+/// Files written so far, and how to undo it.
+#[derive(Default)]
+struct Changes {
+    changed: Vec<PathBuf>,
+    /// Directories that did not exist before, outermost first.
+    created_dirs: Vec<PathBuf>,
+    created_files: Vec<PathBuf>,
+    originals: Vec<(PathBuf, String)>,
+}
+
+impl Changes {
+    fn create(&mut self, path: PathBuf, content: String) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            let missing = parent.ancestors().take_while(|dir| !dir.exists());
+            if let Some(outermost) = missing.last() {
+                self.created_dirs.push(outermost.to_path_buf());
+            }
+            std::fs::create_dir_all(parent)
+                .map_err(|err| format!("cannot create {}: {err}", parent.display()))?;
+        }
+
+        self.created_files.push(path.clone());
+        self.write(path, content)
+    }
+
+    fn replace(&mut self, path: PathBuf, original: String, content: String) -> Result<(), String> {
+        self.originals.push((path.clone(), original));
+        self.write(path, content)
+    }
+
+    fn write(&mut self, path: PathBuf, content: String) -> Result<(), String> {
+        std::fs::write(&path, content)
+            .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+        self.changed.push(path);
+        Ok(())
+    }
+
+    /// Best effort: restores the modified files, then removes the new ones.
+    fn undo(self) {
+        for (path, original) in self.originals {
+            let _ = std::fs::write(path, original);
+        }
+        for path in self.created_files {
+            let _ = std::fs::remove_file(path);
+        }
+        for dir in self.created_dirs.iter().rev() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+/// Day number of an item named after a day crate, such as `"day12"` or `day12::Day12`.
+fn day_of(item: &str) -> Option<u8> {
+    let digits = item.trim_start_matches(['"', ' ']).strip_prefix("day")?;
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+
+    digits[..end].parse().ok()
+}
+
+/// Inserts `line` among the `lines` that belong to a day, keeping days sorted.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    line: String,
+    day: u8,
+    day_of_line: fn(&str) -> Option<u8>,
+) {
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of_line(line).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+
+    let position = match days.iter().find(|(_, d)| *d > day) {
+        Some((i, _)) => *i,
+        None => days.last().map_or(lines.len(), |(i, _)| i + 1),
+    };
+    lines.insert(position, line);
+}
+
+/// Adds the crate to the `members` of the workspace manifest.
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("no `members` list in the workspace")?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unterminated `members` list")?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().to_string())
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+    insert_sorted(&mut members, format!("\"day{day}\""), day, day_of);
+
+    Ok(format!(
+        "{}{}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    ))
+}
+
+/// Adds the crate to the dependencies of the runner.
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+    if !lines.iter().any(|line| day_of(line).is_some()) {
+        return Err("no day dependency to insert the new one next to".to_string());
+    }
+
+    insert_sorted(
+        &mut lines,
+        format!("day{day} = {{ path = \"../day{day}\" }}"),
+        day,
+        |line| day_of(line).filter(|_| line.contains("path")),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the `DAYS` known by the runner.
+fn register(registry: &str, day: u8) -> Result<String, String> {
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+    let day_of_entry = |line: &str| day_of(line.trim().strip_prefix("Day::of::<")?);
+    if !lines.iter().any(|line| day_of_entry(line).is_some()) {
+        return Err("no registered day to insert the new one next to".to_string());
+    }
+
+    insert_sorted(
+        &mut lines,
+        format!("    Day::of::<day{day}::Day{day}>(),"),
+        day,
+        day_of_entry,
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::scaffold::{add_dependency, add_member, new_day, register};
+    use std::path::Path;
+
+    #[test]
+    fn tst_add_member() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day1\", \"day10\"]\n";
+
+        assert_eq!(
+            add_member(manifest, 6).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"day1\", \"day6\", \"day10\"]\n"
+        );
+    }
+
+    #[test]
+    fn tst_add_dependency() {
+        let manifest =
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\nserde = \"1\"\n";
+
+        assert_eq!(
+            add_dependency(manifest, 2).unwrap(),
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nserde = \"1\"\n"
+        );
+    }
+
+    #[test]
+    fn tst_register() {
+        let registry = "pub const DAYS: &[Day] = &[\n    Day::of::<day1::Day1>(),\n    Day::of::<day3::Day3>(),\n];\n";
+
+        assert_eq!(
+            register(registry, 2).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    Day::of::<day1::Day1>(),\n    Day::of::<day2::Day2>(),\n    Day::of::<day3::Day3>(),\n];\n"
+        );
+    }
+
+    #[test]
+    fn tst_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day1\"]\n";
+        write("Cargo.toml", manifest);
+        write(
+            "aoc/Cargo.toml",
+            "[dependencies]\nday1 = { path = \"../day1\" }\n",
+        );
+        write(
+            "aoc/src/registry.rs",
+            "pub const DAYS: &[Day] = &[\n    Day::of::<day1::Day1>(),\n];\n",
+        );
+        // A file in the way of the inputs makes the scaffolding fail halfway
+        write("inputs", "");
+
+        assert!(new_day(&root, 6, "").is_err());
+        assert!(!root.join("day6").exists());
+        assert_eq!(read("Cargo.toml"), manifest);
+
+        std::fs::remove_file(root.join("inputs")).unwrap();
+        let changed = new_day(&root, 6, r#"Say "hi" \o/"#).unwrap();
+        assert_eq!(changed.len(), 7);
+        assert!(read("day6/src/lib.rs").contains(r#"TITLE: &'static str = "Say \"hi\" \\o/";"#));
+        assert!(read("aoc/src/registry.rs").contains("day6::Day6"));
+        assert!(Path::new(&root.join("inputs/day6/cases.toml")).is_file());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+use crate::input::Input;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// Outcome of comparing an answer with the known-good one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// Known-good answer of `part` for an input file, stored next to it:
+/// `inputs/day3/full.txt` is answered by `inputs/day3/full_answer_1.txt`.
+pub fn answer_path(input: &Path, part: u8) -> PathBuf {
+    let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+    input.with_file_name(format!("{stem}_answer_{part}.txt"))
+}
+
+pub fn verify(input: &Input, part: u8, answer: &str) -> Verdict {
+    let Some(path) = &input.path else {
+        return Verdict::Unknown;
+    };
+
+    match std::fs::read_to_string(answer_path(path, part)) {
+        Ok(expected) if expected.trim() == answer => Verdict::Pass,
+        Ok(expected) => Verdict::Fail {
+            expected: expected.trim().to_string(),
+        },
+        Err(_) => Verdict::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::input::Input;
+    use crate::verify::{answer_path, verify, Verdict};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn tst_answer_path() {
+        assert_eq!(
+            answer_path(Path::new("inputs/day3/full.txt"), 1),
+            PathBuf::from("inputs/day3/full_answer_1.txt")
+        );
+        assert_eq!(
+            answer_path(Path::new("sample2.txt"), 2),
+            PathBuf::from("sample2_answer_2.txt")
+        );
+    }
+
+    #[test]
+    fn tst_verify() {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("full_answer_1.txt"), "161\n").unwrap();
+
+        let input = Input {
+            path: Some(dir.join("full.txt")),
+            text: String::new(),
+        };
+        assert_eq!(verify(&input, 1, "161"), Verdict::Pass);
+        assert_eq!(
+            verify(&input, 1, "48"),
+            Verdict::Fail {
+                expected: "161".to_string()
+            }
+        );
+
+        // Nothing to compare with
+        assert_eq!(verify(&input, 2, "48"), Verdict::Unknown);
+        let stdin = Input {
+            path: None,
+            text: String::new(),
+        };
+        assert_eq!(verify(&stdin, 1, "161"), Verdict::Unknown);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
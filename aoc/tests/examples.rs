@@ -0,0 +1,93 @@
+//! Runs every example listed in the `inputs/dayN/cases.toml` manifests.
+//!
+//! Each entry of a manifest becomes its own test, so a new regression example
+//! only needs an input file next to the manifest and an entry describing it:
+//!
+//! ```toml
+//! [[case]]
+//! input = "sample.txt"
+//! part = 1
+//! answer = "161"
+//! description = "Optional note shown when the case fails"
+//! ```
+
+use aoc::registry::{Day, DAYS};
+use libtest_mimic::{Arguments, Failed, Trial};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+const INPUTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    case: Vec<Case>,
+}
+
+#[derive(Deserialize)]
+struct Case {
+    input: PathBuf,
+    part: u8,
+    answer: String,
+    description: Option<String>,
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    let trials = DAYS.iter().flat_map(trials).collect();
+
+    libtest_mimic::run(&args, trials).exit();
+}
+
+fn trials(day: &'static Day) -> Vec<Trial> {
+    let name = day.name();
+    let folder = Path::new(INPUTS).join(&name);
+    let manifest_path = folder.join("cases.toml");
+
+    let manifest = match std::fs::read_to_string(&manifest_path) {
+        Ok(manifest) => toml::from_str::<Manifest>(&manifest),
+        // A day without examples simply contributes no test
+        Err(_) => return vec![],
+    };
+
+    let manifest = match manifest {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            let message = format!("{}: {err}", manifest_path.display());
+            return vec![Trial::test(format!("{name}::cases.toml"), move || {
+                Err(message.into())
+            })];
+        }
+    };
+
+    manifest
+        .case
+        .into_iter()
+        .map(|case| {
+            let test_name = format!("{name}::{}::part{}", case.input.display(), case.part);
+            let path = folder.join(&case.input);
+            Trial::test(test_name, move || run_case(day, &path, &case))
+        })
+        .collect()
+}
+
+fn run_case(day: &Day, path: &Path, case: &Case) -> Result<(), Failed> {
+    let location = match &case.description {
+        Some(description) => format!("{} part {} ({description})", path.display(), case.part),
+        None => format!("{} part {}", path.display(), case.part),
+    };
+
+    if !(1..=2).contains(&case.part) {
+        return Err(format!("{location}: part must be 1 or 2").into());
+    }
+
+    let input = std::fs::read_to_string(path)
+        .map_err(|err| format!("{location}: cannot read input: {err}"))?;
+    let answer = day.part(case.part)(input.trim()).map_err(|err| format!("{location}: {err}"))?;
+
+    if answer == case.answer {
+        Ok(())
+    } else {
+        Err(format!("{location}: expected `{}`, got `{answer}`", case.answer).into())
+    }
+}
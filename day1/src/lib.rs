@@ -0,0 +1,87 @@
+use aoc_core::{AnswerError, ParseError, Solution};
+use std::iter::zip;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const NUMBER: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lists(input)
+    }
+
+    /// Main body for solving
+    fn part1((left_list, right_list): &Self::Input) -> Result<String, AnswerError> {
+        let mut left_list = left_list.clone();
+        let mut right_list = right_list.clone();
+
+        left_list.sort();
+        right_list.sort();
+
+        let total: i32 = zip(left_list, right_list)
+            .map(|(x, y)| x.max(y) - x.min(y))
+            .sum();
+
+        Ok(total.to_string())
+    }
+
+    fn part2((left_list, right_list): &Self::Input) -> Result<String, AnswerError> {
+        let similarities = left_list
+            .iter()
+            .map(|x| right_list.iter().filter(|y| *y == x).count() as i32)
+            .collect::<Vec<i32>>();
+
+        let total: i32 = zip(left_list, similarities).map(|(x, y)| x * y).sum();
+
+        Ok(total.to_string())
+    }
+}
+
+fn parse_lists(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let mut ids = line.split_whitespace();
+
+        match (ids.next(), ids.next(), ids.next()) {
+            (Some(left), Some(right), None) => {
+                left_list.push(parse_id(input, left)?);
+                right_list.push(parse_id(input, right)?);
+            }
+            _ => {
+                return Err(ParseError::of(
+                    Day1::NUMBER,
+                    input,
+                    line,
+                    "expected two location ids",
+                ))
+            }
+        }
+    }
+
+    Ok((left_list, right_list))
+}
+
+fn parse_id(input: &str, id: &str) -> Result<i32, ParseError> {
+    id.parse::<i32>()
+        .map_err(|err| ParseError::of(Day1::NUMBER, input, id, err))
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::Day1;
+    use aoc_core::Solution;
+
+    #[test]
+    fn case_malformed() {
+        let err = Day1::parse("3   4\r\n4   3\r\n2   x5\r\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 5, "x5"));
+
+        let err = Day1::parse("3   4\n4   3\n2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "2"));
+    }
+}
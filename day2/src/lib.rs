@@ -0,0 +1,72 @@
+use aoc_core::{AnswerError, ParseError, Solution};
+use itertools::Itertools;
+
+fn value_in_range(value: u32) -> bool {
+    value > 0 && value < 4
+}
+
+fn is_report_safe(levels: &[i32]) -> bool {
+    levels.iter()
+        .tuple_windows()
+        .all(|(left, right)| value_in_range(left.abs_diff(*right)))
+        &&
+        (levels.is_sorted() || levels.iter().rev().is_sorted())
+}
+
+fn is_report_safe_violations(levels: &[i32]) -> bool {
+    for i in 0..levels.len() {
+        let mut sub = Vec::from(&levels[0..i]);
+        sub.extend_from_slice(&levels[i+1..]);
+
+        if is_report_safe(&sub) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const NUMBER: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    /// Main body for solving
+    fn part1(reports: &Self::Input) -> Result<String, AnswerError> {
+        let safe = reports.iter().filter(|a| is_report_safe(a)).count();
+
+        Ok(safe.to_string())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<String, AnswerError> {
+        let safe = reports
+            .iter()
+            .filter(|a| is_report_safe_violations(a))
+            .count();
+
+        Ok(safe.to_string())
+    }
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .lines()
+        .filter(|report_str| !report_str.trim().is_empty())
+        .map(|report_str| {
+            report_str
+                .split_whitespace()
+                .map(|s| {
+                    s.parse::<i32>()
+                        .map_err(|err| ParseError::of(Day2::NUMBER, input, s, err))
+                })
+                .collect()
+        })
+        .collect()
+}
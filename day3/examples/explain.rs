@@ -0,0 +1,53 @@
+//! Traces the evaluation of the second part on a memory dump, read from a
+//! file or from stdin, to see which instructions counted.
+//!
+//! `cargo run -p day3 --example explain -- [--json] [FILE]`
+
+use aoc_core::Solution;
+use day3::registry::Registry;
+use day3::trace::{explain, render, Format};
+use day3::Day3;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut format = Format::Text;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => format = Format::Json,
+            _ => path = Some(arg),
+        }
+    }
+
+    let source = match path {
+        Some(path) => {
+            std::fs::read_to_string(&path).map_err(|err| format!("cannot read {path}: {err}"))
+        }
+        None => {
+            let mut source = String::new();
+            io::stdin()
+                .read_to_string(&mut source)
+                .map(|_| source)
+                .map_err(|err| format!("cannot read stdin: {err}"))
+        }
+    };
+    let parsed = source.and_then(|source| match Day3::parse(&source) {
+        Ok(exprs) => Ok((source, exprs)),
+        Err(err) => Err(err.to_string()),
+    });
+    let (source, exprs) = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let trace = explain(&source, &exprs, Registry::puzzle());
+    print!("{}", render(format, &trace));
+    match trace.error {
+        None => ExitCode::SUCCESS,
+        Some(_) => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,123 @@
+//! Syntax of the instructions hidden in the corrupted memory.
+//!
+//! An instruction is a name directly followed by a parenthesized list of
+//! comma separated numbers, without any space: `mul(2,4)`, `do()`. Which
+//! names exist, and how many arguments they take, is up to a [`Registry`].
+
+use crate::lexer::{Token, TokenKind};
+use crate::registry::{Instruction, Registry};
+
+/// Occurrence of an instruction, with its arguments still as text.
+#[derive(Debug, Clone)]
+pub struct Call<'a, 'r> {
+    pub instruction: &'r Instruction,
+    pub args: Vec<&'a str>,
+    /// Byte span of the call, from the first letter of its name to `)`.
+    pub offset: usize,
+    pub len: usize,
+    /// Number of tokens read, the first one holding the name.
+    pub tokens: usize,
+}
+
+/// Arguments at the start of `tokens`: `(`, numbers separated by commas, `)`.
+///
+/// Returns the text of the numbers and the number of tokens read.
+pub fn parse_args<'a>(tokens: &[Token<'a>]) -> Option<(Vec<&'a str>, usize)> {
+    let mut tokens = tokens.iter().enumerate();
+    if tokens.next()?.1.kind != TokenKind::Open {
+        return None;
+    }
+
+    let mut args = vec![];
+    let mut expect_number = true;
+    for (i, token) in tokens {
+        match (token.kind, expect_number) {
+            (TokenKind::Close, _) if args.is_empty() || !expect_number => {
+                return Some((args, i + 1));
+            }
+            (TokenKind::Number, true) => args.push(token.text),
+            (TokenKind::Comma, false) => {}
+            _ => return None,
+        }
+        expect_number = !expect_number;
+    }
+
+    None
+}
+
+/// Call of one of the instructions of `registry` starting at the first
+/// token, which must be a word ending with the name of the instruction:
+/// corrupted memory can glue any letters before it, as in `xmul(2,4)` or
+/// `undo()`.
+///
+/// When several names match, the longest one wins. The width of the
+/// arguments is left for the caller to check.
+pub fn parse_call<'a, 'r>(tokens: &[Token<'a>], registry: &'r Registry) -> Option<Call<'a, 'r>> {
+    let word = tokens
+        .first()
+        .filter(|token| token.kind == TokenKind::Word)?;
+    let instruction = registry
+        .iter()
+        .filter(|instruction| word.text.ends_with(&instruction.name))
+        .max_by_key(|instruction| instruction.name.len())?;
+
+    let (args, read) = parse_args(&tokens[1..])?;
+    if args.len() != instruction.arity {
+        return None;
+    }
+
+    let offset = word.end() - instruction.name.len();
+    Some(Call {
+        instruction,
+        args,
+        offset,
+        len: tokens[read].end() - offset,
+        tokens: read + 1,
+    })
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::grammar::{parse_args, parse_call};
+    use crate::lexer::Lexer;
+    use crate::registry::Registry;
+
+    #[test]
+    fn test_grammar() {
+        let registry = Registry::puzzle();
+
+        let tokens = Lexer::new("(1,23)").collect::<Vec<_>>();
+        assert_eq!(parse_args(&tokens), Some((vec!["1", "23"], 5)));
+
+        let tokens = Lexer::new("xmul(2,4)%").collect::<Vec<_>>();
+        let call = parse_call(&tokens, registry).unwrap();
+        assert_eq!(
+            (call.instruction.name.as_str(), call.args),
+            ("mul", vec!["2", "4"])
+        );
+        assert_eq!((call.offset, call.len, call.tokens), (1, 8, 6));
+
+        let tokens = Lexer::new("undon't()").collect::<Vec<_>>();
+        assert_eq!(
+            parse_call(&tokens, registry).unwrap().instruction.name,
+            "don't"
+        );
+
+        let invalid = [
+            "mul(1)",
+            "mul(1,2,3)",
+            "do(1)",
+            "mul (1,2)",
+            "mul(1,2",
+            "mulx(1,2)",
+            "add(1,2)",
+        ];
+        assert!(invalid.iter().all(|source| {
+            let tokens = Lexer::new(source).collect::<Vec<_>>();
+            parse_call(&tokens, registry).is_none()
+        }));
+
+        let tokens = Lexer::new("max(1,2,3)").collect::<Vec<_>>();
+        assert!(parse_call(&tokens, &Registry::arithmetic()).is_some());
+    }
+}
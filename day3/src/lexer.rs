@@ -0,0 +1,103 @@
+//! Splits the corrupted memory into words, numbers and punctuation.
+
+/// What a token is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Letters, apostrophes and underscores, such as `mul` or `don't`.
+    Word,
+    /// Decimal digits.
+    Number,
+    Open,
+    Close,
+    Comma,
+    /// Any other single character, whitespace included.
+    Other,
+}
+
+/// Slice of the source, located by its byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    pub offset: usize,
+}
+
+impl Token<'_> {
+    /// Offset of the first byte after the token.
+    pub fn end(&self) -> usize {
+        self.offset + self.text.len()
+    }
+}
+
+/// Tokens of a source, in order; every byte belongs to exactly one token.
+pub struct Lexer<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Lexer { source, pos: 0 }
+    }
+}
+
+fn is_word(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '\'' || c == '_'
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.source[self.pos..];
+        let first = rest.chars().next()?;
+
+        let (kind, len) = match first {
+            c if is_word(c) => (TokenKind::Word, rest.find(|c| !is_word(c))),
+            c if c.is_ascii_digit() => {
+                (TokenKind::Number, rest.find(|c: char| !c.is_ascii_digit()))
+            }
+            '(' => (TokenKind::Open, Some(1)),
+            ')' => (TokenKind::Close, Some(1)),
+            ',' => (TokenKind::Comma, Some(1)),
+            c => (TokenKind::Other, Some(c.len_utf8())),
+        };
+        let len = len.unwrap_or(rest.len());
+
+        let token = Token {
+            kind,
+            text: &rest[..len],
+            offset: self.pos,
+        };
+        self.pos += len;
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::lexer::{Lexer, TokenKind};
+
+    #[test]
+    fn test_lexer() {
+        let tokens = Lexer::new("xdon't(12,3)é ").collect::<Vec<_>>();
+        let kinds = tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Word,
+                TokenKind::Open,
+                TokenKind::Number,
+                TokenKind::Comma,
+                TokenKind::Number,
+                TokenKind::Close,
+                TokenKind::Other,
+                TokenKind::Other,
+            ]
+        );
+        assert_eq!((tokens[0].text, tokens[2].text), ("xdon't", "12"));
+        assert_eq!((tokens[6].offset, tokens[6].end()), (12, 14));
+        assert_eq!(Lexer::new("").count(), 0);
+    }
+}
@@ -0,0 +1,611 @@
+use aoc_core::{AnswerError, ParseError, Solution};
+use grammar::Call;
+use lexer::Lexer;
+use registry::{Instruction, Operation, Registry, Semantics};
+use span::{Locator, Rejected, Span};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+pub mod grammar;
+pub mod lexer;
+pub mod registry;
+pub mod render;
+pub mod span;
+pub mod trace;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const NUMBER: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Expression>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_with(input, Registry::puzzle(), &Options::default())
+    }
+
+    /// Main body for solving
+    fn part1(expressions: &Self::Input) -> Result<String, AnswerError> {
+        answer(1, eval(expressions, Registry::puzzle()))
+    }
+
+    fn part2(expressions: &Self::Input) -> Result<String, AnswerError> {
+        answer(2, eval_control(expressions, Registry::puzzle()))
+    }
+}
+
+fn answer(part: u8, result: Result<i64, EvalError>) -> Result<String, AnswerError> {
+    result
+        .map(|value| value.to_string())
+        .map_err(|err| AnswerError::new(Day3::NUMBER, part, err))
+}
+
+/// What to do with an instruction whose operand is out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Treat it as more corrupted memory, as the puzzle does.
+    Skip,
+    /// Fail parsing, pointing at the instruction.
+    Error,
+}
+
+/// Parsing options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Number of digits allowed in each operand, instead of the width
+    /// declared by each instruction of the grammar.
+    pub digits: Option<RangeInclusive<usize>>,
+    /// Applies to operands wider than allowed, and to values not fitting an `i64`.
+    pub policy: Policy,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            digits: None,
+            policy: Policy::Skip,
+        }
+    }
+}
+
+/// Why the instructions could not be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// The sum of the values does not fit an `i64`.
+    Overflow,
+    /// The instruction, such as `div(7,0)`, has no value.
+    Undefined(String),
+    /// No instruction of that name is registered.
+    Unknown(String),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Overflow => write!(f, "overflow: the result does not fit a 64-bit integer"),
+            EvalError::Undefined(call) => write!(f, "undefined: `{call}` has no value"),
+            EvalError::Unknown(name) => write!(f, "unknown instruction `{name}`"),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+/// Instruction found in the corrupted memory, along with where it was found.
+#[derive(Eq, PartialEq, Debug)]
+pub enum Expression {
+    Mul {
+        span: Span,
+        p1: i64,
+        p2: i64,
+    },
+    Parens {
+        span: Span,
+        p1: Option<i64>,
+        p2: Option<i64>,
+    },
+    Do {
+        span: Span,
+    },
+    Dont {
+        span: Span,
+    },
+    /// Any other instruction of the registry.
+    Op {
+        span: Span,
+        name: String,
+        args: Vec<i64>,
+    },
+    Ignore,
+}
+
+impl Expression {
+    /// Name and arguments of the instruction, `None` for what is not one.
+    pub fn instruction(&self) -> Option<(&str, Vec<i64>)> {
+        match self {
+            Expression::Mul { p1, p2, .. } => Some(("mul", vec![*p1, *p2])),
+            Expression::Do { .. } => Some(("do", vec![])),
+            Expression::Dont { .. } => Some(("don't", vec![])),
+            Expression::Op { name, args, .. } => Some((name, args.clone())),
+            Expression::Parens { .. } | Expression::Ignore => None,
+        }
+    }
+
+    /// Where the expression was found, `None` for [`Expression::Ignore`].
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Expression::Mul { span, .. }
+            | Expression::Parens { span, .. }
+            | Expression::Do { span }
+            | Expression::Dont { span }
+            | Expression::Op { span, .. } => Some(*span),
+            Expression::Ignore => None,
+        }
+    }
+}
+
+/// Argument list at the start of `expr`, either empty or a pair of numbers,
+/// or [`Expression::Ignore`] when there is none.
+pub fn parse_parens(expr: &[u8]) -> Expression {
+    let source = String::from_utf8_lossy(expr);
+    let tokens = Lexer::new(&source).collect::<Vec<_>>();
+
+    let Some((args, read)) = grammar::parse_args(&tokens) else {
+        return Expression::Ignore;
+    };
+    let Ok(mut args) = args
+        .iter()
+        .map(|arg| arg.parse())
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return Expression::Ignore;
+    };
+
+    match args.len() {
+        0 | 2 => {
+            let p2 = args.pop();
+            Expression::Parens {
+                span: Locator::new(&source).locate(0, tokens[read - 1].end()),
+                p1: args.pop(),
+                p2,
+            }
+        }
+        _ => Expression::Ignore,
+    }
+}
+
+/// Expression for the instruction `name` at the start of `expr`, or
+/// [`Expression::Ignore`] when there is none, including when its operands are
+/// out of the range of the puzzle.
+fn parse_instruction(expr: &[u8], name: &str) -> Expression {
+    let source = String::from_utf8_lossy(expr);
+    let tokens = Lexer::new(&source).collect::<Vec<_>>();
+
+    match grammar::parse_call(&tokens, Registry::puzzle()) {
+        Some(call) if call.offset == 0 && call.instruction.name == name => {
+            let span = Locator::new(&source).locate(0, call.len);
+            build(&call, span, &Options::default()).unwrap_or(Expression::Ignore)
+        }
+        _ => Expression::Ignore,
+    }
+}
+
+/// `mul` instruction at the start of `expr`.
+pub fn parse_mul(expr: &[u8]) -> Expression {
+    parse_instruction(expr, "mul")
+}
+
+/// `don't` instruction at the start of `expr`.
+pub fn peek_dont(expr: &[u8]) -> Expression {
+    parse_instruction(expr, "don't")
+}
+
+/// `do` instruction at the start of `expr`.
+pub fn parse_do(expr: &[u8]) -> Expression {
+    parse_instruction(expr, "do")
+}
+
+/// Expression of a call found at `span`, once its arguments are read as
+/// numbers, or why an operand is out of range.
+fn build(call: &Call, span: Span, options: &Options) -> Result<Expression, String> {
+    let digits = options.digits.as_ref().unwrap_or(&call.instruction.digits);
+    let mut args = vec![];
+
+    for arg in &call.args {
+        let value = match (digits.contains(&arg.len()), arg.parse::<i64>()) {
+            (true, Ok(value)) => value,
+            (false, _) => {
+                return Err(format!(
+                    "operand `{arg}` has {} digits, expected {} to {}",
+                    arg.len(),
+                    digits.start(),
+                    digits.end()
+                ))
+            }
+            (true, Err(err)) => return Err(format!("operand `{arg}`: {err}")),
+        };
+        args.push(value);
+    }
+
+    let expr = match (call.instruction.name.as_str(), args.as_slice()) {
+        ("mul", [p1, p2]) => Expression::Mul {
+            span,
+            p1: *p1,
+            p2: *p2,
+        },
+        ("do", []) => Expression::Do { span },
+        ("don't", []) => Expression::Dont { span },
+        (name, _) => Expression::Op {
+            span,
+            name: name.to_string(),
+            args,
+        },
+    };
+
+    Ok(expr)
+}
+
+/// Sum of the values of the instructions, whatever `don't` says.
+pub fn eval(exprs: &[Expression], registry: &Registry) -> Result<i64, EvalError> {
+    run(exprs, registry, false, |_| {})
+}
+
+/// Sum of the values of the instructions enabled by the last `do` or `don't`
+/// before them.
+pub fn eval_control(exprs: &[Expression], registry: &Registry) -> Result<i64, EvalError> {
+    run(exprs, registry, true, |_| {})
+}
+
+/// Value of `expr`, `None` for an instruction without one such as `do()`.
+pub fn value(expr: &Expression, registry: &Registry) -> Result<Option<i64>, EvalError> {
+    let Some((name, args)) = expr.instruction() else {
+        return Ok(None);
+    };
+
+    match &lookup(registry, name)?.semantics {
+        Semantics::Value(operation) => apply(operation, name, &args).map(Some),
+        Semantics::Enable | Semantics::Disable => Ok(None),
+    }
+}
+
+fn lookup<'r>(registry: &'r Registry, name: &str) -> Result<&'r Instruction, EvalError> {
+    registry
+        .get(name)
+        .ok_or_else(|| EvalError::Unknown(name.to_string()))
+}
+
+fn apply(operation: &Operation, name: &str, args: &[i64]) -> Result<i64, EvalError> {
+    operation(args).ok_or_else(|| EvalError::Undefined(call_text(name, args)))
+}
+
+/// Instruction rebuilt from its name and arguments, such as `mul(2,4)`.
+fn call_text(name: &str, args: &[i64]) -> String {
+    let args = args.iter().map(i64::to_string).collect::<Vec<_>>();
+    format!("{name}({})", args.join(","))
+}
+
+/// What running one instruction did.
+struct Event<'a> {
+    span: Span,
+    semantics: &'a Semantics,
+    /// Whether the instructions with a value are enabled after this one.
+    enabled: bool,
+    /// Added to the result, only for an enabled instruction with a value.
+    value: Option<i64>,
+    total: i64,
+}
+
+/// Evaluates the instructions, following `do` and `don't` when `control` is
+/// set, and reports each step to `observe`.
+fn run(
+    exprs: &[Expression],
+    registry: &Registry,
+    control: bool,
+    mut observe: impl FnMut(Event),
+) -> Result<i64, EvalError> {
+    let mut result: i64 = 0;
+    let mut enabled = true;
+
+    for expr in exprs {
+        let (Some((name, args)), Some(span)) = (expr.instruction(), expr.span()) else {
+            continue;
+        };
+        let instruction = lookup(registry, name)?;
+
+        let mut value = None;
+        match &instruction.semantics {
+            Semantics::Value(operation) if enabled => {
+                let added = apply(operation, name, &args)?;
+                result = result.checked_add(added).ok_or(EvalError::Overflow)?;
+                value = Some(added);
+            }
+            Semantics::Value(_) => {}
+            Semantics::Enable => enabled = true,
+            Semantics::Disable => enabled = !control,
+        }
+
+        observe(Event {
+            span,
+            semantics: &instruction.semantics,
+            enabled,
+            value,
+            total: result,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Instructions of `registry` found in `source`, with operands limited
+/// according to `options`.
+pub fn parse_with(
+    source: &str,
+    registry: &Registry,
+    options: &Options,
+) -> Result<Vec<Expression>, ParseError> {
+    let found = parse_located(source, registry, options)?;
+    Ok(found.into_iter().filter_map(Result::ok).collect())
+}
+
+/// Every call of an instruction of `registry` found in `source`, including the
+/// ones rejected because of their operands when `options` skip them.
+pub fn parse_located(
+    source: &str,
+    registry: &Registry,
+    options: &Options,
+) -> Result<Vec<Result<Expression, Rejected>>, ParseError> {
+    let tokens = Lexer::new(source).collect::<Vec<_>>();
+    let mut locator = Locator::new(source);
+    let mut tree = vec![];
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let Some(call) = grammar::parse_call(&tokens[i..], registry) else {
+            i += 1;
+            continue;
+        };
+
+        let span = locator.locate(call.offset, call.len);
+        let expr = build(&call, span, options);
+        if let (Err(err), Policy::Error) = (&expr, options.policy) {
+            return Err(ParseError::at(
+                Day3::NUMBER,
+                source,
+                call.offset,
+                call.len,
+                err,
+            ));
+        }
+        tree.push(expr.map_err(|reason| Rejected { span, reason }));
+        i += call.tokens;
+    }
+
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::registry::{Instruction, Registry};
+    use crate::span::Span;
+    use crate::{
+        answer, eval, eval_control, parse_do, parse_mul, parse_parens, parse_with, peek_dont, Day3,
+        EvalError, Expression, Options, Policy,
+    };
+    use aoc_core::{AnswerError, Solution};
+
+    /// Span of the first `len` bytes of a single line.
+    fn head(len: usize) -> Span {
+        Span {
+            offset: 0,
+            len,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    #[test]
+    fn test_parens() {
+        assert_eq!(
+            parse_parens("(123,456)".as_bytes()),
+            Expression::Parens {
+                span: head(9),
+                p1: Some(123),
+                p2: Some(456)
+            }
+        );
+        assert_eq!(
+            parse_parens("()".as_bytes()),
+            Expression::Parens {
+                span: head(2),
+                p1: None,
+                p2: None
+            }
+        );
+
+        let input_invalid = [
+            "( 123 , 456 )",
+            "mul(4*",
+            "mul(6,9!",
+            "?(12,34)",
+            "(12,,34)",
+            "(12 34)",
+            "((12,,34))",
+        ];
+        assert!(input_invalid
+            .iter()
+            .map(|input| parse_parens(input.as_bytes()))
+            .all(|value| value == Expression::Ignore));
+    }
+
+    #[test]
+    fn test_peek_mul() {
+        let input_valid = "mul(123,456)";
+        assert_eq!(
+            parse_mul(input_valid.as_bytes()),
+            Expression::Mul {
+                span: head(12),
+                p1: 123,
+                p2: 456
+            }
+        );
+
+        let input_invalid = [
+            "mul( 123 , 456 )",
+            "mul(4*",
+            "mul(6,9!",
+            "mul?(12,34)",
+            "mul(12,,34)",
+            "mul(12 34)",
+            "mul((12,,34))",
+        ];
+        assert!(input_invalid
+            .iter()
+            .map(|input| parse_parens(input.as_bytes()))
+            .all(|value| value == Expression::Ignore));
+    }
+
+    #[test]
+    fn test_peek_dont() {
+        let input_valid = "don't()";
+        assert_eq!(
+            peek_dont(input_valid.as_bytes()),
+            Expression::Dont { span: head(7) }
+        );
+
+        let input_invalid = ["don't( )", "don't(())"];
+        assert!(input_invalid
+            .iter()
+            .map(|input| parse_parens(input.as_bytes()))
+            .all(|value| value == Expression::Ignore));
+    }
+
+    #[test]
+    fn test_peek_do() {
+        let input_valid = "do()";
+        assert_eq!(
+            parse_do(input_valid.as_bytes()),
+            Expression::Do { span: head(4) }
+        );
+
+        let input_invalid = ["do( )", "do(())"];
+        assert!(input_invalid
+            .iter()
+            .map(|input| parse_parens(input.as_bytes()))
+            .all(|value| value == Expression::Ignore));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input_invalid = ["(12)", "(,34)", "(12,)"];
+        assert!(input_invalid
+            .iter()
+            .map(|input| parse_parens(input.as_bytes()))
+            .all(|value| value == Expression::Ignore));
+        assert_eq!(
+            parse_parens("(99999999999999999999,1)".as_bytes()),
+            Expression::Ignore
+        );
+        assert_eq!(parse_mul("mul(1234,5)".as_bytes()), Expression::Ignore);
+
+        let strict = Options {
+            digits: None,
+            policy: Policy::Error,
+        };
+        let err =
+            parse_with("mul(2,4)\nxmul(99999999999,1)", Registry::puzzle(), &strict).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "mul(99999999999,1)");
+        assert_eq!(
+            err.reason,
+            "operand `99999999999` has 11 digits, expected 1 to 3"
+        );
+
+        // Instructions cut by the end of the memory are not run
+        assert_eq!(Day3::solve("mul(2,4)mul(12,3").unwrap(), "8");
+    }
+
+    #[test]
+    fn test_operand_limits() {
+        let puzzle = Registry::puzzle();
+
+        // Operands of more than 3 digits are corrupted memory in the puzzle
+        assert_eq!(Day3::solve("mul(2,4)mul(1234,5)mul(0002,3)").unwrap(), "8");
+
+        let wide = Options {
+            digits: Some(1..=20),
+            policy: Policy::Skip,
+        };
+        let source = "mul(1234,5)mul(99999999999999999999,1)";
+        assert_eq!(
+            eval(&parse_with(source, puzzle, &wide).unwrap(), puzzle),
+            Ok(6170)
+        );
+
+        let strict = Options {
+            policy: Policy::Error,
+            ..wide
+        };
+        let err = parse_with(source, puzzle, &strict).unwrap_err();
+        assert_eq!((err.column, err.text.len()), (12, 27));
+        assert!(err.reason.contains("too large"));
+
+        let huge = parse_with("mul(999999999,999999999)", puzzle, &strict).unwrap();
+        assert_eq!(eval(&huge, puzzle), Ok(999999998000000001));
+        let more = parse_with(&"mul(999999999,999999999)".repeat(10), puzzle, &strict).unwrap();
+        assert_eq!(eval(&more, puzzle), Err(EvalError::Overflow));
+        assert_eq!(
+            Day3::part2(&more),
+            Err(AnswerError::new(3, 2, EvalError::Overflow))
+        );
+    }
+
+    #[test]
+    fn test_registry_eval() {
+        let mut registry = Registry::arithmetic();
+        registry.register(Instruction::value("neg", 1, |args| args[0].checked_neg()));
+
+        let source = "add(1,2)xsub(3,10)don't()max(4,9,2)do()neg(5)mul(2,3)";
+        let exprs = parse_with(source, &registry, &Options::default()).unwrap();
+        assert_eq!(exprs.len(), 7);
+        assert_eq!(
+            exprs[0],
+            Expression::Op {
+                span: head(8),
+                name: "add".to_string(),
+                args: vec![1, 2]
+            }
+        );
+        let span = exprs[1].span().unwrap();
+        assert_eq!((span.column, span.text(source)), (10, "sub(3,10)"));
+        assert_eq!(eval(&exprs, &registry), Ok(6));
+        assert_eq!(eval_control(&exprs, &registry), Ok(-3));
+
+        // The puzzle knows none of them
+        let puzzle = Registry::puzzle();
+        assert_eq!(
+            parse_with(source, puzzle, &Options::default())
+                .unwrap()
+                .len(),
+            3
+        );
+        assert_eq!(
+            eval(&exprs, puzzle),
+            Err(EvalError::Unknown("add".to_string()))
+        );
+
+        let zero = parse_with("div(7,0)", &registry, &Options::default()).unwrap();
+        let err = eval(&zero, &registry).unwrap_err();
+        assert_eq!(err.to_string(), "undefined: `div(7,0)` has no value");
+
+        // Every error is reported as such, never as an answer
+        let err = answer(1, eval(&zero, &registry)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day3, part 1: undefined: `div(7,0)` has no value"
+        );
+        let err = answer(2, eval_control(&exprs, puzzle)).unwrap_err();
+        assert_eq!(err, AnswerError::new(3, 2, "unknown instruction `add`"));
+    }
+}
@@ -0,0 +1,166 @@
+//! Instructions understood by the scanner, and what running them does.
+
+use std::fmt::{Debug, Formatter};
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+/// Value of an instruction for its arguments, `None` when it has none, such
+/// as on an overflow or a division by zero.
+pub type Operation = Box<dyn Fn(&[i64]) -> Option<i64> + Send + Sync>;
+
+/// What running an instruction does.
+pub enum Semantics {
+    /// Adds the value to the result, unless a `Disable` instruction ran last.
+    Value(Operation),
+    /// Lets the following instructions add to the result.
+    Enable,
+    /// Stops the following instructions from adding to the result.
+    Disable,
+}
+
+/// Instruction of the language: a name directly followed by its arguments,
+/// as in `mul(2,4)`.
+pub struct Instruction {
+    pub name: String,
+    /// Number of arguments.
+    pub arity: usize,
+    /// Number of digits allowed in each argument.
+    pub digits: RangeInclusive<usize>,
+    pub semantics: Semantics,
+}
+
+impl Instruction {
+    /// Operand width of the puzzle.
+    pub const DIGITS: RangeInclusive<usize> = 1..=3;
+
+    /// Instruction adding `operation` of its `arity` arguments to the result.
+    pub fn value(
+        name: &str,
+        arity: usize,
+        operation: impl Fn(&[i64]) -> Option<i64> + Send + Sync + 'static,
+    ) -> Self {
+        Instruction {
+            name: name.to_string(),
+            arity,
+            digits: Instruction::DIGITS,
+            semantics: Semantics::Value(Box::new(operation)),
+        }
+    }
+
+    /// Instruction without arguments enabling the ones that follow.
+    pub fn enable(name: &str) -> Self {
+        Instruction {
+            name: name.to_string(),
+            arity: 0,
+            digits: 0..=0,
+            semantics: Semantics::Enable,
+        }
+    }
+
+    /// Instruction without arguments disabling the ones that follow.
+    pub fn disable(name: &str) -> Self {
+        Instruction {
+            name: name.to_string(),
+            arity: 0,
+            digits: 0..=0,
+            semantics: Semantics::Disable,
+        }
+    }
+}
+
+impl Debug for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.name, self.arity)
+    }
+}
+
+/// Set of instructions, looked up by name.
+#[derive(Debug, Default)]
+pub struct Registry {
+    instructions: Vec<Instruction>,
+}
+
+impl Registry {
+    /// Registry without any instruction.
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// `mul`, `do` and `don't`, as in the puzzle.
+    pub fn puzzle() -> &'static Registry {
+        static PUZZLE: OnceLock<Registry> = OnceLock::new();
+        PUZZLE.get_or_init(Registry::base)
+    }
+
+    fn base() -> Registry {
+        let mut registry = Registry::new();
+        registry
+            .register(Instruction::value("mul", 2, |args| {
+                args[0].checked_mul(args[1])
+            }))
+            .register(Instruction::enable("do"))
+            .register(Instruction::disable("don't"));
+        registry
+    }
+
+    /// The instructions of the puzzle, along with `add`, `sub` and `div` of
+    /// 2 arguments and `max` of 3.
+    pub fn arithmetic() -> Registry {
+        let mut registry = Registry::base();
+        registry
+            .register(Instruction::value("add", 2, |args| {
+                args[0].checked_add(args[1])
+            }))
+            .register(Instruction::value("sub", 2, |args| {
+                args[0].checked_sub(args[1])
+            }))
+            .register(Instruction::value("div", 2, |args| {
+                args[0].checked_div(args[1])
+            }))
+            .register(Instruction::value("max", 3, |args| {
+                args.iter().copied().max()
+            }));
+        registry
+    }
+
+    /// Adds `instruction`, replacing the one of the same name if any.
+    pub fn register(&mut self, instruction: Instruction) -> &mut Self {
+        self.instructions
+            .retain(|known| known.name != instruction.name);
+        self.instructions.push(instruction);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Instruction> {
+        self.instructions.iter().find(|known| known.name == name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Instruction> {
+        self.instructions.iter()
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::registry::{Instruction, Registry, Semantics};
+
+    #[test]
+    fn test_registry() {
+        let puzzle = Registry::puzzle();
+        assert_eq!(puzzle.iter().count(), 3);
+        assert!(matches!(
+            puzzle.get("don't").unwrap().semantics,
+            Semantics::Disable
+        ));
+        assert!(puzzle.get("add").is_none());
+
+        let mut registry = Registry::arithmetic();
+        let Semantics::Value(div) = &registry.get("div").unwrap().semantics else {
+            panic!("div has a value");
+        };
+        assert_eq!((div(&[7, 2]), div(&[7, 0])), (Some(3), None));
+
+        registry.register(Instruction::value("div", 2, |_| Some(0)));
+        assert_eq!(registry.iter().filter(|i| i.name == "div").count(), 1);
+    }
+}
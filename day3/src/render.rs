@@ -0,0 +1,116 @@
+//! Reports on where instructions were found in the corrupted memory, and
+//! what each of them is worth.
+
+use crate::registry::Registry;
+use crate::span::{Rejected, Span};
+use crate::{value, Expression};
+
+const ACCEPTED: &str = "\x1b[1;32m";
+const REJECTED: &str = "\x1b[31m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// How fragments of the source are told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Accepted instructions in `[...]`, rejected ones in `{...}`.
+    Plain,
+    /// Accepted instructions in bold green, rejected ones in red, and the
+    /// corrupted memory around them dimmed, for terminals.
+    Ansi,
+}
+
+/// Renders `source` with the instructions `found` in it marked, in order.
+pub fn annotate(source: &str, found: &[Result<Expression, Rejected>], style: Style) -> String {
+    let mut out = String::new();
+    let mut pos = 0;
+
+    let corrupted = |out: &mut String, text: &str| match style {
+        _ if text.is_empty() => {}
+        Style::Plain => out.push_str(text),
+        Style::Ansi => out.push_str(&format!("{DIM}{text}{RESET}")),
+    };
+
+    for found in found {
+        let Some(span) = span_of(found).filter(|span| span.offset >= pos) else {
+            continue;
+        };
+        corrupted(&mut out, &source[pos..span.offset]);
+
+        let text = span.text(source);
+        match (style, found.is_ok()) {
+            (Style::Plain, true) => out.push_str(&format!("[{text}]")),
+            (Style::Plain, false) => out.push_str(&format!("{{{text}}}")),
+            (Style::Ansi, true) => out.push_str(&format!("{ACCEPTED}{text}{RESET}")),
+            (Style::Ansi, false) => out.push_str(&format!("{REJECTED}{text}{RESET}")),
+        }
+        pos = span.end();
+    }
+    corrupted(&mut out, &source[pos..]);
+
+    out
+}
+
+/// One line per instruction `found` in `source`, such as `mul(2,4) at 1:2
+/// contributed 8`, with values according to `registry`.
+pub fn report(source: &str, found: &[Result<Expression, Rejected>], registry: &Registry) -> String {
+    let lines = found.iter().filter_map(|found| {
+        let span = span_of(found)?;
+        let outcome = match found {
+            Err(rejected) => format!("rejected: {}", rejected.reason),
+            Ok(expr) => match value(expr, registry) {
+                Ok(Some(value)) => format!("contributed {value}"),
+                Ok(None) => "contributed nothing".to_string(),
+                Err(err) => format!("failed: {err}"),
+            },
+        };
+        Some(format!("{} at {span} {outcome}", span.text(source)))
+    });
+
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+fn span_of(found: &Result<Expression, Rejected>) -> Option<Span> {
+    match found {
+        Ok(expr) => expr.span(),
+        Err(rejected) => Some(rejected.span),
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::registry::Registry;
+    use crate::render::{annotate, report, Style};
+    use crate::{parse_located, Options};
+
+    const SAMPLE: &str = include_str!("../../inputs/day3/sample2.txt");
+
+    #[test]
+    fn test_annotate() {
+        let registry = Registry::puzzle();
+        let source = "xmul(2,4)mul(1234,5)\n?do()";
+        let found = parse_located(source, registry, &Options::default()).unwrap();
+
+        assert_eq!(found.len(), 3);
+        assert_eq!(
+            annotate(source, &found, Style::Plain),
+            "x[mul(2,4)]{mul(1234,5)}\n?[do()]"
+        );
+        assert_eq!(
+            report(source, &found, registry),
+            "mul(2,4) at 1:2 contributed 8\n\
+             mul(1234,5) at 1:10 rejected: operand `1234` has 4 digits, expected 1 to 3\n\
+             do() at 2:2 contributed nothing"
+        );
+
+        let found = parse_located(SAMPLE, registry, &Options::default()).unwrap();
+        assert_eq!(
+            annotate(SAMPLE.trim_end(), &found, Style::Plain),
+            "x[mul(2,4)]&mul[3,7]!^[don't()]_[mul(5,5)]+mul(32,64](\
+             [mul(11,8)]un[do()]?[mul(8,5)])"
+        );
+
+        let ansi = annotate(source, &found[..0], Style::Ansi);
+        assert_eq!(ansi, format!("\x1b[2m{source}\x1b[0m"));
+    }
+}
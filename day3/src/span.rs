@@ -0,0 +1,113 @@
+//! Where instructions were found in the corrupted memory.
+
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// Bytes of the source covered by an instruction, along with the line and
+/// column of its first character.
+///
+/// Lines and columns are 1-based, columns count characters rather than bytes,
+/// as in [`aoc_core::ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub offset: usize,
+    pub len: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// Offset of the first byte after the span.
+    pub fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    /// Text of the span in the source it was found in.
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.offset..self.end()]
+    }
+}
+
+/// Renders `line:column`.
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Computes spans in a source, reading it only once when they come in order.
+pub struct Locator<'a> {
+    source: &'a str,
+    /// Offset up to which lines and columns are known.
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Locator<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Locator {
+            source,
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Span of the `len` bytes at `offset`, which must be a character boundary.
+    pub fn locate(&mut self, offset: usize, len: usize) -> Span {
+        if offset < self.pos {
+            *self = Locator::new(self.source);
+        }
+
+        for c in self.source[self.pos..offset].chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.pos = offset;
+
+        Span {
+            offset,
+            len,
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+/// Instruction call found in the source whose operands were rejected, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejected {
+    pub span: Span,
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::span::Locator;
+    use aoc_core::ParseError;
+
+    #[test]
+    fn test_locator() {
+        let source = "ab\nçd mul\n\nx";
+        let mut locator = Locator::new(source);
+
+        let span = locator.locate(7, 3);
+        assert_eq!((span.line, span.column, span.text(source)), (2, 4, "mul"));
+        assert_eq!(span.to_string(), "2:4");
+
+        let last = locator.locate(12, 1);
+        assert_eq!((last.line, last.column), (4, 1));
+
+        // Going back starts over from the beginning
+        let first = locator.locate(1, 1);
+        assert_eq!((first.line, first.column), (1, 2));
+
+        let err = ParseError::at(3, source, 7, 3, "");
+        assert_eq!((err.line, err.column), (span.line, span.column));
+    }
+}
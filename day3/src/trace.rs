@@ -0,0 +1,232 @@
+//! Step by step account of the evaluation with `do` and `don't`, to see
+//! which instructions counted.
+
+use crate::registry::{Registry, Semantics};
+use crate::span::Span;
+use crate::{run, EvalError, Expression};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// One instruction run by [`explain`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    /// As written in the source, such as `mul(2,4)`.
+    pub instruction: String,
+    /// Where the instruction is in the source.
+    pub span: Span,
+    /// Whether the instructions with a value count after this one.
+    pub enabled: bool,
+    /// Added to the total, only set for an enabled instruction with a value.
+    pub value: Option<i64>,
+    /// Set for an instruction with a value run while disabled.
+    pub skipped: bool,
+    pub total: i64,
+}
+
+/// Totals of a trace.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    /// Instructions with a value that were added to the total.
+    pub counted: usize,
+    /// Instructions with a value run while disabled, the `mul` calls after a
+    /// `don't()` in the puzzle.
+    pub skipped: usize,
+    /// Number of times the instructions got disabled while enabled.
+    pub regions: usize,
+    pub total: i64,
+}
+
+/// Steps of an evaluation, up to the error that stopped it if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+    pub summary: Summary,
+    pub error: Option<EvalError>,
+}
+
+/// How a trace is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One aligned line per step, then the summary.
+    Text,
+    Json,
+}
+
+/// Evaluates `exprs`, parsed from `source`, as
+/// [`eval_control`](crate::eval_control) does, keeping every step along the way.
+pub fn explain(source: &str, exprs: &[Expression], registry: &Registry) -> Trace {
+    let mut steps = vec![];
+    let mut summary = Summary::default();
+    let mut enabled = true;
+
+    let result = run(exprs, registry, true, |event| {
+        let skipped = matches!(event.semantics, Semantics::Value(_)) && event.value.is_none();
+        match event.semantics {
+            Semantics::Value(_) if event.value.is_some() => summary.counted += 1,
+            Semantics::Value(_) => summary.skipped += 1,
+            Semantics::Disable if enabled => summary.regions += 1,
+            Semantics::Enable | Semantics::Disable => {}
+        }
+        enabled = event.enabled;
+
+        steps.push(Step {
+            instruction: event.span.text(source).to_string(),
+            span: event.span,
+            enabled: event.enabled,
+            value: event.value,
+            skipped,
+            total: event.total,
+        });
+    });
+    summary.total = steps.last().map_or(0, |step| step.total);
+
+    Trace {
+        steps,
+        summary,
+        error: result.err(),
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    steps: &'a [Step],
+    summary: &'a Summary,
+    error: Option<String>,
+}
+
+pub fn render(format: Format, trace: &Trace) -> String {
+    match format {
+        Format::Text => render_text(trace),
+        Format::Json => {
+            let record = Record {
+                steps: &trace.steps,
+                summary: &trace.summary,
+                error: trace.error.as_ref().map(EvalError::to_string),
+            };
+            serde_json::to_string_pretty(&record).expect("Can serialize a trace") + "\n"
+        }
+    }
+}
+
+fn render_text(trace: &Trace) -> String {
+    let mut out = String::new();
+    let width = trace
+        .steps
+        .iter()
+        .map(|step| step.instruction.len())
+        .max()
+        .unwrap_or(0);
+    let locations = trace
+        .steps
+        .iter()
+        .map(|step| step.span.to_string())
+        .collect::<Vec<_>>();
+    let location_width = locations.iter().map(String::len).max().unwrap_or(0);
+
+    for (step, location) in trace.steps.iter().zip(&locations) {
+        let enabled = if step.enabled { "on" } else { "off" };
+        let value = match (step.value, step.skipped) {
+            (Some(value), _) => format!("+{value}"),
+            (None, true) => "skipped".to_string(),
+            (None, false) => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            "{:<width$}  {location:<location_width$}  {enabled:<3}  {value:>9}  {:>12}",
+            step.instruction, step.total
+        );
+    }
+
+    let summary = &trace.summary;
+    let _ = writeln!(
+        out,
+        "{} counted, {} skipped in {} disabled region(s), total {}",
+        summary.counted, summary.skipped, summary.regions, summary.total
+    );
+    if let Some(err) = &trace.error {
+        let _ = writeln!(out, "stopped: {err}");
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::registry::{Instruction, Registry};
+    use crate::span::Span;
+    use crate::trace::{explain, render, Format, Step};
+    use crate::{eval_control, Day3, EvalError};
+    use aoc_core::Solution;
+
+    const SAMPLE: &str = include_str!("../../inputs/day3/sample2.txt");
+
+    #[test]
+    fn test_explain() {
+        let registry = Registry::puzzle();
+        let exprs = Day3::parse(SAMPLE).unwrap();
+        let trace = explain(SAMPLE, &exprs, registry);
+
+        assert_eq!(trace.steps.len(), 6);
+        assert_eq!(
+            trace.steps[2],
+            Step {
+                instruction: "mul(5,5)".to_string(),
+                span: Span {
+                    offset: 28,
+                    len: 8,
+                    line: 1,
+                    column: 29,
+                },
+                enabled: false,
+                value: None,
+                skipped: true,
+                total: 8,
+            }
+        );
+        let summary = &trace.summary;
+        assert_eq!(
+            (summary.counted, summary.skipped, summary.regions),
+            (2, 2, 1)
+        );
+        assert_eq!(Ok(summary.total), eval_control(&exprs, registry));
+        assert_eq!(trace.error, None);
+
+        let text = render(Format::Text, &trace);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "mul(2,4)   1:2   on          +8             8");
+        assert_eq!(lines[3], "mul(11,8)  1:49  off    skipped             8");
+        assert_eq!(
+            lines[6],
+            "2 counted, 2 skipped in 1 disabled region(s), total 48"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&render(Format::Json, &trace)).unwrap();
+        assert_eq!(json["steps"][5]["value"], 40);
+        assert_eq!(json["steps"][5]["span"]["column"], 65);
+        assert_eq!(json["summary"]["skipped"], 2);
+        assert!(json["error"].is_null());
+
+        // Instructions are shown as written, on the line they are on
+        let source = "mul(2,4)\nxmul(002,3)";
+        let exprs = Day3::parse(source).unwrap();
+        let trace = explain(source, &exprs, registry);
+        let text = render(Format::Text, &trace);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[1], "mul(002,3)  2:2  on          +6            14");
+    }
+
+    #[test]
+    fn test_explain_error() {
+        // Each call is worth 999^6, and the tenth one overflows
+        let mut registry = Registry::new();
+        registry.register(Instruction::value("mul", 2, |args| args[0].checked_pow(6)));
+        let source = "mul(999,999)".repeat(10);
+        let exprs = Day3::parse(&source).unwrap();
+        let trace = explain(&source, &exprs, &registry);
+
+        assert_eq!(trace.steps.len(), 9);
+        assert_eq!(trace.error, Some(EvalError::Overflow));
+        assert!(render(Format::Text, &trace)
+            .ends_with("stopped: overflow: the result does not fit a 64-bit integer\n"));
+    }
+}
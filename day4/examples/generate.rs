@@ -0,0 +1,98 @@
+//! Writes a random word search and its answers, in the layout `aoc run --verify` reads.
+//!
+//! `cargo run -p day4 --example generate -- --rows 140 --cols 140 --seed 7 puzzle.txt`
+//! writes `puzzle.txt`, `puzzle_answer_1.txt` and `puzzle_answer_2.txt`.
+
+use clap::Parser;
+use day4::generate::{generate, Spec};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Generate a day4 word search with known answers")]
+struct Args {
+    /// Where to write the grid; answers are written next to it
+    output: PathBuf,
+
+    #[arg(long, default_value_t = 140)]
+    rows: usize,
+
+    #[arg(long, default_value_t = 140)]
+    cols: usize,
+
+    /// Words to plant, separated by commas, each one as many times as `--count`
+    #[arg(long, value_delimiter = ',', default_value = "XMAS")]
+    words: Vec<String>,
+
+    /// Number of times each word is planted
+    #[arg(long, default_value_t = 100)]
+    count: usize,
+
+    /// Number of MAS crosses planted
+    #[arg(long, default_value_t = 50)]
+    crosses: usize,
+
+    /// Letters filling the rest of the grid
+    #[arg(long, default_value = "ABCDEFGHIJKLMNOPQRSTUVWXYZ")]
+    noise: String,
+
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+fn write(path: &Path, contents: String) -> bool {
+    match std::fs::write(path, contents + "\n") {
+        Ok(()) => {
+            println!("wrote {}", path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("error: cannot write {}: {err}", path.display());
+            false
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let spec = Spec {
+        rows: args.rows,
+        cols: args.cols,
+        words: args
+            .words
+            .iter()
+            .flat_map(|word| std::iter::repeat_n(word.clone(), args.count))
+            .collect(),
+        crosses: args.crosses,
+        noise: args.noise.chars().collect(),
+        seed: args.seed,
+    };
+
+    let puzzle = generate(&spec);
+    println!(
+        "planted {} of {} words and {} of {} crosses",
+        puzzle.planted,
+        spec.words.len(),
+        puzzle.planted_crosses,
+        spec.crosses
+    );
+
+    let stem = args
+        .output
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let answer = |part: u8| {
+        args.output
+            .with_file_name(format!("{stem}_answer_{part}.txt"))
+    };
+
+    let written = write(&args.output, puzzle.grid.to_string())
+        && write(&answer(1), puzzle.xmas.to_string())
+        && write(&answer(2), puzzle.x_mas.to_string());
+
+    match written {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,53 @@
+//! Compares the serial and parallel scans of day4 on a large generated grid.
+//!
+//! `cargo run --release -p day4 --features parallel --example scan -- [SIZE] [ITERATIONS]`,
+//! with the backend named by `AOC_DAY4_BACKEND`.
+
+use aoc_grid::Grid;
+use day4::random::{noise, Rng};
+use day4::{count_x_mas, count_xmas, Backend, Scan};
+use std::time::{Duration, Instant};
+
+type Count = fn(&Grid<char>, Backend, Scan) -> usize;
+
+/// Fastest of `iterations` runs, along with the count they found.
+fn time(iterations: usize, mut run: impl FnMut() -> usize) -> (usize, Duration) {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let count = run();
+            (count, start.elapsed())
+        })
+        .min_by_key(|(_, elapsed)| *elapsed)
+        .expect("At least one iteration")
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let size = args
+        .next()
+        .map_or(2_000, |arg| arg.parse().expect("SIZE is a number"));
+    let iterations = args
+        .next()
+        .map_or(3, |arg| arg.parse().expect("ITERATIONS is a number"))
+        .max(1);
+
+    let grid = noise(size, size, &['X', 'M', 'A', 'S'], &mut Rng::new(2024));
+    let backend = Backend::from_env().unwrap_or_else(|err| panic!("{err}"));
+    println!(
+        "{size}x{size} grid, {backend:?} backend, best of {iterations}, {} threads",
+        rayon::current_num_threads()
+    );
+
+    let counts: [(&str, Count); 2] = [("XMAS", count_xmas), ("X-MAS", count_x_mas)];
+    for (name, count) in counts {
+        let (serial, serial_time) = time(iterations, || count(&grid, backend, Scan::Serial));
+        let (parallel, parallel_time) = time(iterations, || count(&grid, backend, Scan::Parallel));
+        assert_eq!(serial, parallel, "both scans find the same {name}");
+
+        println!(
+            "{name:<6} {serial:>12}  serial {serial_time:>10.1?}  parallel {parallel_time:>10.1?}  x{:.2}",
+            serial_time.as_secs_f64() / parallel_time.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,33 @@
+//! Counts the words of a grid of any size, read from a file or from stdin.
+//!
+//! `cargo run --release -p day4 --example stream -- [FILE]`
+
+use day4::stream::count_stream;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let counts = match std::env::args().nth(1) {
+        Some(path) => match File::open(&path) {
+            Ok(file) => count_stream(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("error: cannot open {path}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => count_stream(io::stdin().lock()),
+    };
+
+    match counts {
+        Ok(counts) => {
+            println!("XMAS: {}", counts.xmas);
+            println!("X-MAS: {}", counts.x_mas);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
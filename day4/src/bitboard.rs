@@ -0,0 +1,165 @@
+//! Letters of a grid as bitmasks, one bit per column, so that a word is
+//! looked for in 64 cells at once with shifts and bitwise ANDs.
+
+use aoc_grid::{Direction, Grid};
+
+const BITS: usize = u64::BITS as usize;
+
+/// One bitmask per letter and per row of a grid, bit `c` of word `c / 64` of
+/// a row being set when the cell at column `c` holds the letter.
+#[derive(Debug, Clone)]
+pub struct Bitboard {
+    rows: usize,
+    width: usize,
+    planes: Vec<(char, Vec<u64>)>,
+}
+
+impl Bitboard {
+    /// Masks of `letters` in `grid`; words made of other letters are never found.
+    pub fn new(grid: &Grid<char>, letters: &[char]) -> Bitboard {
+        let width = grid.cols().div_ceil(BITS);
+        let planes = letters
+            .iter()
+            .map(|letter| {
+                let mut plane = vec![0; grid.rows() * width];
+                for (pos, _) in grid.iter().filter(|(_, value)| *value == letter) {
+                    plane[pos.row * width + pos.col / BITS] |= 1 << (pos.col % BITS);
+                }
+                (*letter, plane)
+            })
+            .collect();
+
+        Bitboard {
+            rows: grid.rows(),
+            width,
+            planes,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn plane(&self, letter: char) -> Option<&[u64]> {
+        self.planes
+            .iter()
+            .find(|(known, _)| *known == letter)
+            .map(|(_, plane)| plane.as_slice())
+    }
+
+    /// Word `i` of `row` in `plane`, moved so that bit `c` tells about the
+    /// cell at column `c + shift`. Cells outside of the grid are cleared.
+    fn word(&self, plane: &[u64], row: usize, i: usize, shift: isize) -> u64 {
+        let words = &plane[row * self.width..(row + 1) * self.width];
+        let get = |i: isize| usize::try_from(i).ok().and_then(|i| words.get(i)).copied();
+
+        let i = i as isize + shift.div_euclid(BITS as isize);
+        let bits = shift.rem_euclid(BITS as isize) as u32;
+        match bits {
+            0 => get(i).unwrap_or(0),
+            _ => get(i).unwrap_or(0) >> bits | get(i + 1).unwrap_or(0) << (BITS as u32 - bits),
+        }
+    }
+
+    /// Number of times `word` is read from a cell of `row` following `dir`.
+    pub fn count_row(&self, word: &[char], dir: Direction, row: usize) -> usize {
+        if word.is_empty() {
+            return 0;
+        }
+
+        // Row and column shift of each letter, relative to the first one
+        let mut letters = vec![];
+        for (k, letter) in word.iter().enumerate() {
+            let Some(row) = row.checked_add_signed(k as isize * dir.dy()) else {
+                return 0;
+            };
+            match self.plane(*letter) {
+                Some(plane) if row < self.rows => letters.push((plane, row, k as isize * dir.dx())),
+                _ => return 0,
+            }
+        }
+
+        (0..self.width)
+            .map(|i| {
+                letters
+                    .iter()
+                    .fold(!0, |found, (plane, row, shift)| {
+                        found & self.word(plane, *row, i, *shift)
+                    })
+                    .count_ones() as usize
+            })
+            .sum()
+    }
+
+    /// Number of 3 letter `word` crossed in an X, centered on a cell of `row`.
+    pub fn count_cross_row(&self, word: [char; 3], row: usize) -> usize {
+        let [first, center, last] = word.map(|letter| self.plane(letter));
+        let (Some(first), Some(center), Some(last)) = (first, center, last) else {
+            return 0;
+        };
+        if row == 0 || row + 1 >= self.rows {
+            return 0;
+        }
+
+        (0..self.width)
+            .map(|i| {
+                let at = |plane, row, shift| self.word(plane, row, i, shift);
+                let (up, down) = (row - 1, row + 1);
+
+                // Each diagonal reads the word in one way or the other
+                let diagonal = (at(first, up, -1) & at(last, down, 1))
+                    | (at(last, up, -1) & at(first, down, 1));
+                let anti_diagonal = (at(first, up, 1) & at(last, down, -1))
+                    | (at(last, up, 1) & at(first, down, -1));
+
+                (at(center, row, 0) & diagonal & anti_diagonal).count_ones() as usize
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::bitboard::Bitboard;
+    use crate::random::{noise, Rng};
+    use crate::{count_x_mas, count_xmas, Backend, Day4, Scan, MAS, XMAS};
+    use aoc_core::Solution;
+    use aoc_grid::Direction;
+
+    #[test]
+    fn tst_bitboard_sample() {
+        let grid = Day4::parse(include_str!("../../inputs/day4/sample.txt")).unwrap();
+        let board = Bitboard::new(&grid, &XMAS);
+
+        let xmas = (0..board.rows())
+            .flat_map(|row| Direction::all().map(move |dir| (row, dir)))
+            .map(|(row, dir)| board.count_row(&XMAS, dir, row))
+            .sum::<usize>();
+        let x_mas = (0..board.rows())
+            .map(|row| board.count_cross_row(MAS, row))
+            .sum::<usize>();
+        assert_eq!((xmas, x_mas), (18, 9));
+
+        assert_eq!(board.count_row(&['X', 'Y'], Direction::Right, 0), 0);
+    }
+
+    #[test]
+    fn tst_bitboard_random() {
+        let sizes = [(1, 1), (4, 4), (3, 130), (130, 3), (70, 64), (65, 129)];
+
+        for (seed, (rows, cols)) in sizes.into_iter().enumerate() {
+            let grid = noise(rows, cols, &XMAS, &mut Rng::new(seed as u64));
+
+            assert_eq!(
+                count_xmas(&grid, Backend::Bitboard, Scan::Serial),
+                count_xmas(&grid, Backend::Cells, Scan::Serial),
+                "XMAS in a {rows}x{cols} grid"
+            );
+            assert_eq!(
+                count_x_mas(&grid, Backend::Bitboard, Scan::Serial),
+                count_x_mas(&grid, Backend::Cells, Scan::Serial),
+                "X-MAS in a {rows}x{cols} grid"
+            );
+        }
+    }
+}
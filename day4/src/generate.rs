@@ -0,0 +1,193 @@
+//! Random word search puzzles, reproducible from a seed, along with their answers.
+
+use crate::random::Rng;
+use crate::search::{find_stencil, find_words, Stencil, Symmetry};
+use crate::MAS;
+use aoc_grid::{Direction, Grid, Position};
+
+/// What to put in a generated puzzle.
+#[derive(Debug, Clone)]
+pub struct Spec {
+    pub rows: usize,
+    pub cols: usize,
+    /// Words planted in straight lines, in random directions.
+    pub words: Vec<String>,
+    /// Number of MAS crosses planted.
+    pub crosses: usize,
+    /// Letters filling the cells left empty, which are `.` when there are none.
+    pub noise: Vec<char>,
+    pub seed: u64,
+}
+
+impl Default for Spec {
+    fn default() -> Self {
+        Spec {
+            rows: 140,
+            cols: 140,
+            words: vec!["XMAS".to_string(); 100],
+            crosses: 50,
+            noise: ('A'..='Z').collect(),
+            seed: 0,
+        }
+    }
+}
+
+/// Generated grid and its answers.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub grid: Grid<char>,
+    /// Number of words of the spec that found room in the grid.
+    pub planted: usize,
+    /// Number of MAS crosses that found room in the grid.
+    pub planted_crosses: usize,
+    /// Answer of the first part, XMAS read in any direction.
+    pub xmas: usize,
+    /// Answer of the second part, MAS crossed in an X.
+    pub x_mas: usize,
+}
+
+/// Attempts at placing each word or cross before giving up on it.
+const ATTEMPTS: usize = 100;
+
+/// Plants the words and crosses of `spec`, then fills the grid with noise.
+///
+/// Planted letters may share a cell when they agree, and noise can spell more
+/// words, so the answers are counted on the final grid with the generic search
+/// engine rather than derived from what was planted.
+pub fn generate(spec: &Spec) -> Puzzle {
+    let mut rng = Rng::new(spec.seed);
+    let mut cells: Grid<Option<char>> = Grid::filled(spec.rows, spec.cols, None);
+
+    let mut planted = 0;
+    for word in &spec.words {
+        let letters = word.chars().collect::<Vec<_>>();
+        let placed = (0..ATTEMPTS).any(|_| {
+            let start = random_position(&cells, &mut rng);
+            let dir = *rng.pick(&Direction::ALL);
+            let path = (0..letters.len()).try_fold(vec![], |mut path, _| {
+                let pos = match path.last() {
+                    None => start?,
+                    Some(pos) => cells.step(*pos, dir)?,
+                };
+                path.push(pos);
+                Some(path)
+            });
+            path.is_some_and(|path| {
+                plant(&mut cells, path.into_iter().zip(letters.iter().copied()))
+            })
+        });
+        planted += usize::from(placed);
+    }
+
+    let mut planted_crosses = 0;
+    for _ in 0..spec.crosses {
+        let placed = (0..ATTEMPTS).any(|_| {
+            let Some(center) = random_position(&cells, &mut rng) else {
+                return false;
+            };
+            let mut letters = vec![(center, MAS[1])];
+            for dir in [Direction::DownRight, Direction::DownLeft] {
+                let (first, last) = match rng.below(2) {
+                    0 => (MAS[0], MAS[2]),
+                    _ => (MAS[2], MAS[0]),
+                };
+                match (cells.step(center, dir.opposite()), cells.step(center, dir)) {
+                    (Some(before), Some(after)) => letters.extend([(before, first), (after, last)]),
+                    _ => return false,
+                }
+            }
+            plant(&mut cells, letters)
+        });
+        planted_crosses += usize::from(placed);
+    }
+
+    let grid = cells.map(|cell| match (cell, spec.noise.is_empty()) {
+        (Some(letter), _) => *letter,
+        (None, true) => '.',
+        (None, false) => *rng.pick(&spec.noise),
+    });
+
+    let x_mas = Stencil::parse("M.S\n.A.\nM.S").expect("X-MAS is a valid stencil");
+    Puzzle {
+        xmas: find_words(&grid, &["XMAS"]).len(),
+        x_mas: find_stencil(&grid, &x_mas, Symmetry::Rotations).len(),
+        grid,
+        planted,
+        planted_crosses,
+    }
+}
+
+fn random_position<T>(grid: &Grid<T>, rng: &mut Rng) -> Option<Position> {
+    (grid.rows() > 0 && grid.cols() > 0)
+        .then(|| Position::new(rng.below(grid.rows()), rng.below(grid.cols())))
+}
+
+/// Writes the letters when every cell is free or already holds the same
+/// letter, and at least one of them is free so that no placement is repeated.
+fn plant(
+    cells: &mut Grid<Option<char>>,
+    letters: impl IntoIterator<Item = (Position, char)>,
+) -> bool {
+    let letters = letters.into_iter().collect::<Vec<_>>();
+    let fits = letters
+        .iter()
+        .all(|(pos, letter)| cells[*pos].is_none_or(|known| known == *letter));
+    if !fits || letters.iter().all(|(pos, _)| cells[*pos].is_some()) {
+        return false;
+    }
+
+    for (pos, letter) in letters {
+        cells[pos] = Some(letter);
+    }
+    true
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::generate::{generate, Spec};
+    use crate::{Day4, XMAS};
+    use aoc_core::Solution;
+
+    #[test]
+    fn tst_generate() {
+        let spec = Spec {
+            rows: 30,
+            cols: 40,
+            words: vec!["XMAS".to_string(); 20],
+            crosses: 10,
+            noise: vec!['Q', 'Z'],
+            seed: 2024,
+        };
+        let puzzle = generate(&spec);
+
+        assert_eq!((puzzle.grid.rows(), puzzle.grid.cols()), (30, 40));
+        assert_eq!((puzzle.planted, puzzle.planted_crosses), (20, 10));
+        assert!(puzzle.xmas >= puzzle.planted);
+        assert!(puzzle.x_mas >= puzzle.planted_crosses);
+        assert_eq!(Day4::part1(&puzzle.grid).unwrap(), puzzle.xmas.to_string());
+        assert_eq!(Day4::part2(&puzzle.grid).unwrap(), puzzle.x_mas.to_string());
+
+        let again = generate(&spec);
+        assert_eq!(again.grid, puzzle.grid);
+
+        let text = puzzle.grid.to_string();
+        assert_eq!(Day4::solve(&text).unwrap(), puzzle.xmas.to_string());
+    }
+
+    #[test]
+    fn tst_generate_dense() {
+        // Noise from the letters of the words spells many more of them
+        for seed in 0..8 {
+            let puzzle = generate(&Spec {
+                rows: 25,
+                cols: 25,
+                noise: XMAS.to_vec(),
+                seed,
+                ..Spec::default()
+            });
+
+            assert_eq!(Day4::part1(&puzzle.grid).unwrap(), puzzle.xmas.to_string());
+            assert_eq!(Day4::part2(&puzzle.grid).unwrap(), puzzle.x_mas.to_string());
+        }
+    }
+}
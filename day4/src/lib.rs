@@ -0,0 +1,393 @@
+use aoc_core::{AnswerError, ParseError, Solution};
+use aoc_grid::{Direction, Grid, Position, Ragged, Segmentation};
+use bitboard::Bitboard;
+use std::env::VarError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+pub mod bitboard;
+pub mod generate;
+pub mod random;
+pub mod render;
+pub mod search;
+pub mod stream;
+
+/// Environment variable selecting the [`Backend`] used to solve the puzzle.
+pub const BACKEND_VAR: &str = "AOC_DAY4_BACKEND";
+
+const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+const MAS: [char; 3] = ['M', 'A', 'S'];
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const NUMBER: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input, Ragged::Reject)
+    }
+
+    /// Main body for solving
+    fn part1(grid: &Self::Input) -> Result<String, AnswerError> {
+        Ok(count_xmas(grid, backend(1)?, Scan::default()).to_string())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<String, AnswerError> {
+        Ok(count_x_mas(grid, backend(2)?, Scan::default()).to_string())
+    }
+}
+
+/// Configured backend, an invalid one leaving `part` without an answer
+/// rather than timing another backend than asked.
+fn backend(part: u8) -> Result<Backend, AnswerError> {
+    Backend::configured().map_err(|err| AnswerError::new(Day4::NUMBER, part, err))
+}
+
+/// How words are matched against the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// Letter after letter, from every cell holding the first one.
+    #[default]
+    Cells,
+    /// 64 cells at a time, on the bitmasks of each letter.
+    Bitboard,
+}
+
+impl Backend {
+    /// Backend named by `AOC_DAY4_BACKEND`, the default one when unset.
+    pub fn from_env() -> Result<Backend, String> {
+        match std::env::var(BACKEND_VAR) {
+            Ok(name) => name.parse(),
+            Err(VarError::NotPresent) => Ok(Backend::default()),
+            Err(err) => Err(err.to_string()),
+        }
+        .map_err(|err| format!("{BACKEND_VAR}: {err}"))
+    }
+
+    /// [`Backend::from_env`], read once per process.
+    pub fn configured() -> Result<Backend, String> {
+        static CONFIGURED: OnceLock<Result<Backend, String>> = OnceLock::new();
+
+        CONFIGURED.get_or_init(Backend::from_env).clone()
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Backend::Cells => write!(f, "cells"),
+            Backend::Bitboard => write!(f, "bitboard"),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cells" => Ok(Backend::Cells),
+            "bitboard" => Ok(Backend::Bitboard),
+            _ => Err(format!(
+                "unknown backend `{s}`, expected `cells` or `bitboard`"
+            )),
+        }
+    }
+}
+
+/// How the rows of a grid are visited when counting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scan {
+    Serial,
+    /// Rows are split between the threads of the rayon pool.
+    #[cfg(feature = "parallel")]
+    Parallel,
+}
+
+impl Default for Scan {
+    /// Parallel when the `parallel` feature is enabled.
+    fn default() -> Self {
+        #[cfg(feature = "parallel")]
+        return Scan::Parallel;
+        #[cfg(not(feature = "parallel"))]
+        return Scan::Serial;
+    }
+}
+
+/// Number of XMAS read in any direction.
+pub fn count_xmas(grid: &Grid<char>, backend: Backend, scan: Scan) -> usize {
+    if backend == Backend::Bitboard {
+        let board = Bitboard::new(grid, &XMAS);
+        return sum_rows(grid, scan, |row| {
+            Direction::all()
+                .map(|dir| board.count_row(&XMAS, dir, row))
+                .sum()
+        });
+    }
+
+    sum_rows(grid, scan, |row| {
+        cells_of(grid, row, XMAS[0])
+            .map(|cell| {
+                Direction::all()
+                    .map(|dir| grid.count(cell, &XMAS, dir))
+                    .sum::<usize>()
+            })
+            .sum()
+    })
+}
+
+/// Number of MAS crossed in an X.
+pub fn count_x_mas(grid: &Grid<char>, backend: Backend, scan: Scan) -> usize {
+    if backend == Backend::Bitboard {
+        let board = Bitboard::new(grid, &MAS);
+        return sum_rows(grid, scan, |row| board.count_cross_row(MAS, row));
+    }
+
+    sum_rows(grid, scan, |row| {
+        cells_of(grid, row, MAS[1])
+            .map(|cell| {
+                // Both diagonals must read MAS, starting one step before the A
+                Direction::diagonal()
+                    .filter_map(|dir| Some((grid.step(cell, dir.opposite())?, dir)))
+                    .map(|(start, dir)| grid.count(start, &MAS, dir))
+                    .sum::<usize>()
+            })
+            .filter(|value| value == &2)
+            .count()
+    })
+}
+
+/// Cells of `row` holding `letter`.
+fn cells_of(grid: &Grid<char>, row: usize, letter: char) -> impl Iterator<Item = Position> + '_ {
+    grid.row(row)
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .filter(move |(_, value)| **value == letter)
+        .map(move |(col, _)| Position::new(row, col))
+}
+
+fn sum_rows(grid: &Grid<char>, scan: Scan, count: impl Fn(usize) -> usize + Send + Sync) -> usize {
+    match scan {
+        Scan::Serial => (0..grid.rows()).map(count).sum(),
+        #[cfg(feature = "parallel")]
+        Scan::Parallel => {
+            use rayon::prelude::*;
+            (0..grid.rows()).into_par_iter().map(count).sum()
+        }
+    }
+}
+
+/// Grid of letters, one per grapheme so that accented letters are not split
+/// in several cells, with short rows handled according to `ragged`.
+///
+/// Letters made of several `char`s, such as a letter followed by a combining
+/// accent, are rejected.
+pub fn parse_grid(input: &str, ragged: Ragged<char>) -> Result<Grid<char>, ParseError> {
+    Grid::parse_segments(input, Segmentation::Graphemes, ragged, |letter| {
+        let mut chars = letter.chars();
+        chars.next().filter(|_| chars.next().is_none())
+    })
+    .map_err(|err| ParseError::at(Day4::NUMBER, input, err.offset, err.len, err.kind))
+}
+
+/// Word search over a grid of letters.
+pub trait WordSearch {
+    /// 1 when `word` is read from `pos` following `dir`, 0 otherwise.
+    fn count(&self, pos: impl Into<Position>, word: &[char], dir: Direction) -> usize;
+}
+
+impl WordSearch for Grid<char> {
+    fn count(&self, pos: impl Into<Position>, word: &[char], dir: Direction) -> usize {
+        let mut pos = Some(pos.into());
+
+        for c in word {
+            if let Some(cell) = pos {
+                if self.get(cell) != Some(c) {
+                    return 0;
+                }
+
+                pos = self.step(cell, dir);
+            } else {
+                return 0;
+            }
+        }
+
+        1
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::{parse_grid, Backend, Day4, WordSearch, XMAS};
+    use aoc_core::Solution;
+    use aoc_grid::{Direction, Grid, Position, Ragged};
+    use std::str::FromStr;
+
+    #[test]
+    fn tst_grid() {
+        let input = r#"
+        X2345
+        6M432
+        12A45
+        654S2"#;
+
+        let g = Grid::from_str(input).unwrap();
+
+        assert_eq!(g.cols(), 5);
+        assert_eq!(g.rows(), 4);
+        assert_eq!(g[Position::new(0, 0)], 'X');
+        assert_eq!(g[Position::new(3, 4)], '2');
+        assert_eq!(g.into_iter().last().unwrap().0, Position::new(3, 4));
+    }
+
+    #[test]
+    fn tst_grid_iter() {
+        let input = r#"
+        X2345
+        6M432
+        12A45
+        654S2"#;
+
+        let g = Grid::from_str(input).unwrap();
+        let total = g.into_iter().count();
+
+        assert_eq!(total, 20);
+        assert_eq!(g.into_iter().nth(6).unwrap().1, &'M');
+    }
+
+    #[test]
+    fn tst_grid_contains_down() {
+        let grid = Grid::from_str(
+            r#"
+        X234X
+        MM4M2
+        A2A45
+        SS4S2"#,
+        )
+        .unwrap();
+
+        assert_eq!(grid.count((0, 0), &XMAS, Direction::Down), 1);
+        assert_eq!(grid.count((0, 4), &XMAS, Direction::Down), 0);
+        assert_eq!(grid.count((0, 4), &XMAS, Direction::DownLeft), 1);
+        assert_eq!(grid.count((0, 0), &XMAS, Direction::DownLeft), 0);
+        assert_eq!(grid.count((0, 0), &XMAS, Direction::DownRight), 1);
+        assert_eq!(grid.count((0, 4), &XMAS, Direction::DownRight), 0);
+    }
+
+    #[test]
+    fn tst_grid_contains_up() {
+        let grid = Grid::from_str(
+            r#"
+        S234S
+        AA4A2
+        M2M45
+        XX4X2"#,
+        )
+        .unwrap();
+
+        assert_eq!(grid.count((3, 3), &XMAS, Direction::UpLeft), 1);
+        assert_eq!(grid.count((3, 0), &XMAS, Direction::UpLeft), 0);
+        assert_eq!(grid.count((3, 0), &XMAS, Direction::Up), 1);
+        assert_eq!(grid.count((3, 1), &XMAS, Direction::Up), 0);
+        assert_eq!(grid.count((3, 1), &XMAS, Direction::UpRight), 1);
+        assert_eq!(grid.count((0, 0), &XMAS, Direction::UpRight), 0);
+    }
+
+    #[test]
+    fn tst_grid_contains_leftright() {
+        let grid = Grid::from_str(
+            r#"
+        00000
+        XMAS0
+        0SAMX
+        00000"#,
+        )
+        .unwrap();
+
+        assert_eq!(grid.count((2, 4), &XMAS, Direction::Left), 1);
+        assert_eq!(grid.count((1, 0), &XMAS, Direction::Right), 1);
+        assert_eq!(grid.count((1, 0), &XMAS, Direction::Left), 0);
+        assert_eq!(grid.count((2, 4), &XMAS, Direction::Right), 0);
+    }
+
+    #[test]
+    fn tst_grid_malformed() {
+        let err = Day4::parse("XMAS\r\nXMA\r\nXMAS").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "XMA"));
+
+        assert!(Day4::parse(" \n").is_err());
+
+        let err = Day4::parse("XMAS\nXMÄS\nXMASS").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "XMASS"));
+        assert_eq!(err.reason, "expected 4 columns, found 5");
+
+        let err = Day4::parse("XMAS\nXMA\u{308}S").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn tst_grid_unicode() {
+        let grid = Day4::parse("ÄÖÜ\nßXŒ").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Position::new(1, 2)], 'Œ');
+
+        let grid = parse_grid("XMAS\nX\nXM", Ragged::Pad('.')).unwrap();
+        assert_eq!(grid.to_string(), "XMAS\nX...\nXM..");
+        assert_eq!(Day4::part1(&grid).unwrap(), "1");
+    }
+
+    #[test]
+    fn tst_grid_symmetries() {
+        let grid = Day4::parse(include_str!("../../inputs/day4/sample.txt")).unwrap();
+
+        for (i, grid) in grid.symmetries().iter().enumerate() {
+            assert_eq!(Day4::part1(grid).unwrap(), "18", "XMAS in symmetry {i}");
+            assert_eq!(Day4::part2(grid).unwrap(), "9", "X-MAS in symmetry {i}");
+        }
+
+        let corner = grid.subgrid(0..5, 5..10).unwrap().to_grid();
+        let counts = corner
+            .symmetries()
+            .iter()
+            .map(|grid| (Day4::part1(grid).unwrap(), Day4::part2(grid).unwrap()))
+            .collect::<Vec<_>>();
+        assert!(counts.iter().all(|count| *count == counts[0]));
+    }
+
+    #[test]
+    fn tst_backend_names() {
+        for backend in [Backend::Cells, Backend::Bitboard] {
+            assert_eq!(Backend::from_str(&backend.to_string()), Ok(backend));
+        }
+
+        let err = Backend::from_str("bitbaord").unwrap_err();
+        assert_eq!(
+            err,
+            "unknown backend `bitbaord`, expected `cells` or `bitboard`"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn tst_scan_parallel() {
+        use crate::{count_x_mas, count_xmas, Scan};
+
+        let grid = Day4::parse(include_str!("../../inputs/day4/sample.txt")).unwrap();
+
+        for backend in [Backend::Cells, Backend::Bitboard] {
+            assert_eq!(
+                count_xmas(&grid, backend, Scan::Parallel),
+                count_xmas(&grid, backend, Scan::Serial)
+            );
+            assert_eq!(
+                count_x_mas(&grid, backend, Scan::Parallel),
+                count_x_mas(&grid, backend, Scan::Serial)
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Seeded random numbers and grids, for generated puzzles, tests and benchmarks.
+
+use aoc_grid::Grid;
+
+/// SplitMix64 generator: small, and the same sequence for a seed on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Number in `0..n`, for `n > 0`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Grid of `rows` by `cols` letters drawn from `letters`.
+pub fn noise(rows: usize, cols: usize, letters: &[char], rng: &mut Rng) -> Grid<char> {
+    let cells = (0..rows * cols).map(|_| *rng.pick(letters)).collect();
+    Grid::from_cells(rows, cols, cells)
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::random::{noise, Rng};
+
+    #[test]
+    fn tst_rng() {
+        let mut rng = Rng::new(0);
+        let first = (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>();
+        assert_eq!(first[0], 0xE220_A839_7B1D_CDAF);
+
+        let mut again = Rng::new(0);
+        assert!(first.iter().all(|value| *value == again.next_u64()));
+        assert!((0..100).all(|_| rng.below(3) < 3));
+    }
+
+    #[test]
+    fn tst_noise() {
+        let grid = noise(3, 5, &['X', 'M'], &mut Rng::new(7));
+        assert_eq!((grid.rows(), grid.cols()), (3, 5));
+        assert!(grid.iter().all(|(_, letter)| ['X', 'M'].contains(letter)));
+        assert_eq!(grid, noise(3, 5, &['X', 'M'], &mut Rng::new(7)));
+    }
+}
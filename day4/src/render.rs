@@ -0,0 +1,116 @@
+//! Rendering of a grid with the cells of some matches highlighted, the way
+//! the puzzle illustrates its examples.
+
+use aoc_grid::{Grid, Position};
+
+const BOLD: &str = "\x1b[1;33m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// How cells outside of the matches are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Replaced by `.`, as in the puzzle text.
+    Plain,
+    /// Dimmed, with the matched cells in bold yellow, for terminals.
+    Ansi,
+}
+
+/// Renders `grid` one row per line, keeping only the letters of `cells`.
+pub fn highlight<'a>(
+    grid: &Grid<char>,
+    cells: impl IntoIterator<Item = &'a Position>,
+    style: Style,
+) -> String {
+    let mut matched = Grid::filled(grid.rows(), grid.cols(), false);
+    for pos in cells {
+        if let Some(cell) = matched.get_mut(*pos) {
+            *cell = true;
+        }
+    }
+
+    let mut out = String::new();
+    for row in 0..grid.rows() {
+        if row > 0 {
+            out.push('\n');
+        }
+
+        for col in 0..grid.cols() {
+            let pos = Position::new(row, col);
+            let letter = grid[pos];
+            match (style, matched[pos]) {
+                (Style::Plain, true) => out.push(letter),
+                (Style::Plain, false) => out.push('.'),
+                (Style::Ansi, true) => out.push_str(&format!("{BOLD}{letter}{RESET}")),
+                (Style::Ansi, false) => out.push_str(&format!("{DIM}{letter}{RESET}")),
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::render::{highlight, Style};
+    use crate::search::{find_stencil, find_words, Stencil, Symmetry};
+    use crate::Day4;
+    use aoc_core::Solution;
+    use aoc_grid::Grid;
+    use std::str::FromStr;
+
+    const SAMPLE: &str = include_str!("../../inputs/day4/sample.txt");
+
+    #[test]
+    fn tst_highlight_words() {
+        let grid = Day4::parse(SAMPLE).unwrap();
+        let matches = find_words(&grid, &["XMAS"]);
+
+        let expected = "\
+....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX";
+        let cells = matches.iter().flat_map(|m| &m.cells);
+        assert_eq!(highlight(&grid, cells, Style::Plain), expected);
+    }
+
+    #[test]
+    fn tst_highlight_stencil() {
+        let grid = Day4::parse(SAMPLE).unwrap();
+        let x_mas = Stencil::parse("M.S\n.A.\nM.S").unwrap();
+        let matches = find_stencil(&grid, &x_mas, Symmetry::Rotations);
+
+        let expected = "\
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........";
+        let cells = matches.iter().flat_map(|m| &m.cells);
+        assert_eq!(highlight(&grid, cells, Style::Plain), expected);
+    }
+
+    #[test]
+    fn tst_highlight_ansi() {
+        let grid = Grid::from_str("XM\nAS").unwrap();
+        let matches = find_words(&grid, &["XM"]);
+
+        let rendered = highlight(&grid, matches.iter().flat_map(|m| &m.cells), Style::Ansi);
+        assert_eq!(
+            rendered,
+            "\x1b[1;33mX\x1b[0m\x1b[1;33mM\x1b[0m\n\x1b[2mA\x1b[0m\x1b[2mS\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! Word search over a grid of letters, for any word or two-dimensional pattern.
+
+use aoc_grid::{Direction, Grid, GridError, Position};
+
+/// Sequence of letters read in a straight line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordMatch {
+    pub word: String,
+    pub start: Position,
+    pub dir: Direction,
+    /// Cells covered by the word, from its first letter to its last.
+    pub cells: Vec<Position>,
+}
+
+/// Every sequence of `len` letters, read in any of the 8 directions, that
+/// `accept` agrees with.
+///
+/// A sequence and its reverse are distinct matches: a palindrome is found
+/// once from each end.
+pub fn find_sequences(
+    grid: &Grid<char>,
+    len: usize,
+    mut accept: impl FnMut(&[char]) -> bool,
+) -> Vec<WordMatch> {
+    let mut matches = vec![];
+    let mut cells = Vec::with_capacity(len);
+    let mut letters = Vec::with_capacity(len);
+
+    for start in grid.positions() {
+        for dir in Direction::all() {
+            cells.clear();
+            letters.clear();
+            for (pos, c) in grid.ray(start, dir).take(len) {
+                cells.push(pos);
+                letters.push(*c);
+            }
+
+            if letters.len() == len && accept(&letters) {
+                matches.push(WordMatch {
+                    word: letters.iter().collect(),
+                    start,
+                    dir,
+                    cells: cells.clone(),
+                });
+            }
+        }
+    }
+
+    matches
+}
+
+/// Every occurrence of any of `words`, read in any of the 8 directions.
+pub fn find_words(grid: &Grid<char>, words: &[&str]) -> Vec<WordMatch> {
+    let words = words
+        .iter()
+        .map(|word| word.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut lengths = words
+        .iter()
+        .map(Vec::len)
+        .filter(|len| *len > 0)
+        .collect::<Vec<_>>();
+    lengths.sort();
+    lengths.dedup();
+
+    lengths
+        .into_iter()
+        .flat_map(|len| {
+            find_sequences(grid, len, |letters| {
+                words.iter().any(|word| word == letters)
+            })
+        })
+        .collect()
+}
+
+/// Which transformations of a stencil are also searched for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Only the stencil as written.
+    None,
+    /// The 4 quarter turns of the stencil.
+    Rotations,
+    /// The quarter turns of the stencil and of its mirror image.
+    RotationsAndReflections,
+}
+
+/// How a stencil was transformed to match: mirrored left to right first when
+/// `reflected`, then turned clockwise `quarter_turns` times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Orientation {
+    pub quarter_turns: u8,
+    pub reflected: bool,
+}
+
+/// Two-dimensional pattern of letters, where `None` matches any letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stencil {
+    cells: Grid<Option<char>>,
+}
+
+impl Stencil {
+    /// Parses one row per line, `.` matching any letter.
+    pub fn parse(s: &str) -> Result<Stencil, GridError> {
+        let cells = Grid::parse_with(s, |c| Some((c != '.').then_some(c)))?;
+        Ok(Stencil { cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells.cols()
+    }
+
+    /// Quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Stencil {
+        Stencil {
+            cells: self.cells.rotate90(),
+        }
+    }
+
+    /// Mirror image, left to right.
+    pub fn reflect(&self) -> Stencil {
+        Stencil {
+            cells: self.cells.flip_horizontal(),
+        }
+    }
+
+    /// Distinct transformations of the stencil allowed by `symmetry`.
+    pub fn orientations(&self, symmetry: Symmetry) -> Vec<(Orientation, Stencil)> {
+        let reflections: &[bool] = match symmetry {
+            Symmetry::RotationsAndReflections => &[false, true],
+            _ => &[false],
+        };
+        let quarter_turns = match symmetry {
+            Symmetry::None => 1,
+            _ => 4,
+        };
+
+        let mut orientations: Vec<(Orientation, Stencil)> = vec![];
+        for reflected in reflections {
+            let mut stencil = match reflected {
+                true => self.reflect(),
+                false => self.clone(),
+            };
+
+            for turns in 0..quarter_turns {
+                if !orientations.iter().any(|(_, known)| *known == stencil) {
+                    let orientation = Orientation {
+                        quarter_turns: turns,
+                        reflected: *reflected,
+                    };
+                    orientations.push((orientation, stencil.clone()));
+                }
+                stencil = stencil.rotate_cw();
+            }
+        }
+
+        orientations
+    }
+
+    fn matches_at(&self, grid: &Grid<char>, top_left: Position) -> bool {
+        let rows = top_left.row..top_left.row + self.rows();
+        let cols = top_left.col..top_left.col + self.cols();
+        let Some(area) = grid.subgrid(rows, cols) else {
+            return false;
+        };
+
+        self.cells
+            .iter()
+            .all(|(pos, expected)| expected.is_none_or(|letter| area[pos] == letter))
+    }
+
+    /// Cells of the grid under the letters of the stencil, wildcards excluded.
+    fn letters_at(&self, top_left: Position) -> Vec<Position> {
+        self.cells
+            .iter()
+            .filter(|(_, expected)| expected.is_some())
+            .map(|(pos, _)| Position::new(top_left.row + pos.row, top_left.col + pos.col))
+            .collect()
+    }
+}
+
+/// Placement of a stencil on the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StencilMatch {
+    pub top_left: Position,
+    pub orientation: Orientation,
+    /// Cells covered by the letters of the stencil, wildcards excluded.
+    pub cells: Vec<Position>,
+}
+
+/// Every placement of `stencil`, or of the transformations allowed by `symmetry`.
+pub fn find_stencil(grid: &Grid<char>, stencil: &Stencil, symmetry: Symmetry) -> Vec<StencilMatch> {
+    let orientations = stencil.orientations(symmetry);
+
+    grid.positions()
+        .flat_map(|top_left| {
+            orientations
+                .iter()
+                .filter(move |(_, stencil)| stencil.matches_at(grid, top_left))
+                .map(move |(orientation, stencil)| StencilMatch {
+                    top_left,
+                    orientation: *orientation,
+                    cells: stencil.letters_at(top_left),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::search::{find_sequences, find_stencil, find_words, Stencil, Symmetry};
+    use crate::Day4;
+    use aoc_core::Solution;
+    use aoc_grid::{Direction, Grid, Position};
+    use std::str::FromStr;
+
+    const SAMPLE: &str = include_str!("../../inputs/day4/sample.txt");
+
+    #[test]
+    fn tst_find_words() {
+        let grid = Day4::parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            find_words(&grid, &["XMAS"]).len().to_string(),
+            Day4::part1(&grid).unwrap()
+        );
+        assert_eq!(find_words(&grid, &["XMAS", "SAMX"]).len(), 36);
+
+        let grid = Grid::from_str("XMAS\nMASX").unwrap();
+        let matches = find_words(&grid, &["AS", "MAS"]);
+        assert_eq!(matches.len(), 5);
+        assert!(matches.iter().any(|m| m.word == "MAS"
+            && m.start == Position::new(1, 0)
+            && m.dir == Direction::Right));
+
+        let backwards = find_words(&grid, &["XSAM"]);
+        assert_eq!(backwards.len(), 1);
+        assert_eq!(
+            (backwards[0].start, backwards[0].dir),
+            (Position::new(1, 3), Direction::Left)
+        );
+        assert_eq!(
+            backwards[0].cells,
+            (0..4)
+                .rev()
+                .map(|col| Position::new(1, col))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn tst_find_palindromes() {
+        let grid = Grid::from_str("RACECAR\nXXXXXXX").unwrap();
+        let palindromes =
+            find_sequences(&grid, 5, |letters| letters.iter().eq(letters.iter().rev()));
+
+        let words = palindromes
+            .iter()
+            .filter(|m| m.word == "ACECA" || m.word == "XXXXX");
+        assert_eq!(words.count(), palindromes.len());
+        // Each palindrome is read from both of its ends
+        assert_eq!(palindromes.len(), 2 + 3 * 2);
+    }
+
+    #[test]
+    fn tst_find_stencil() {
+        let grid = Day4::parse(SAMPLE).unwrap();
+        let x_mas = Stencil::parse("M.S\n.A.\nM.S").unwrap();
+
+        assert_eq!(
+            find_stencil(&grid, &x_mas, Symmetry::Rotations)
+                .len()
+                .to_string(),
+            Day4::part2(&grid).unwrap()
+        );
+        assert_eq!(
+            x_mas.orientations(Symmetry::RotationsAndReflections).len(),
+            4
+        );
+
+        let grid = Grid::from_str(".M..M.\nMASSAM\n.S..S.").unwrap();
+        let plus_mas = Stencil::parse(".M.\nMAS\n.S.").unwrap();
+        let matches = find_stencil(&grid, &plus_mas, Symmetry::None);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].top_left, Position::new(0, 0));
+        assert_eq!(find_stencil(&grid, &plus_mas, Symmetry::Rotations).len(), 2);
+    }
+}
@@ -0,0 +1,199 @@
+//! Word search over text read row after row, keeping only the last few rows
+//! in memory so that inputs of any height are counted in constant memory.
+
+use crate::{parse_grid, Day4, MAS, XMAS};
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{GridErrorKind, Ragged};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+/// Answers of both parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    /// Number of XMAS read in any direction.
+    pub xmas: usize,
+    /// Number of MAS crossed in an X.
+    pub x_mas: usize,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "cannot read the grid: {err}"),
+            StreamError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// Counts the words of the grid read from `reader`, one row per line.
+///
+/// Rows follow the rules of [`parse_grid`], except that blank lines are
+/// skipped wherever they are. Only the last `XMAS.len()` rows are kept: a
+/// word is counted when the row holding its lowest letter is read.
+pub fn count_stream(mut reader: impl BufRead) -> Result<Counts, StreamError> {
+    let mut counts = Counts::default();
+    let mut window: VecDeque<Vec<char>> = VecDeque::with_capacity(XMAS.len());
+    let mut line = String::new();
+    let mut number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(counts);
+        }
+        number += 1;
+
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let row = parse_row(text, number, window.front().map(Vec::len))?;
+        if window.len() == XMAS.len() {
+            window.pop_front();
+        }
+        window.push_back(row);
+
+        counts.xmas += xmas_ending_at(&window);
+        counts.x_mas += x_mas_ending_at(&window);
+    }
+}
+
+fn parse_row(text: &str, number: usize, cols: Option<usize>) -> Result<Vec<char>, StreamError> {
+    let located = |mut err: ParseError| {
+        err.line = number;
+        StreamError::Parse(err)
+    };
+
+    let grid = parse_grid(text, Ragged::Reject).map_err(located)?;
+    let row = grid.row(0).unwrap_or_default().to_vec();
+
+    match cols {
+        Some(expected) if expected != row.len() => {
+            let kind = GridErrorKind::Ragged {
+                expected,
+                found: row.len(),
+            };
+            Err(located(ParseError::at(
+                Day4::NUMBER,
+                text,
+                0,
+                text.len(),
+                kind,
+            )))
+        }
+        _ => Ok(row),
+    }
+}
+
+/// Letter at `col` of `row` in the window, `None` outside of it.
+fn at(window: &VecDeque<Vec<char>>, row: usize, col: isize) -> Option<char> {
+    let col = usize::try_from(col).ok()?;
+    window.get(row)?.get(col).copied()
+}
+
+/// Number of XMAS whose lowest letter is on the last row of the window.
+fn xmas_ending_at(window: &VecDeque<Vec<char>>) -> usize {
+    let last = window.len() - 1;
+    let cols = window[last].len() as isize;
+    let reversed = XMAS.iter().rev().copied().collect::<Vec<_>>();
+    let mut count = 0;
+
+    for col in 0..cols {
+        // Along the last row, in both ways
+        for word in [&XMAS[..], &reversed] {
+            let found =
+                (0..word.len()).all(|k| at(window, last, col + k as isize) == Some(word[k]));
+            count += usize::from(found);
+        }
+
+        // Across the whole window, top to bottom, both ways for each slope
+        if window.len() == XMAS.len() {
+            for d_col in [-1, 0, 1] {
+                for word in [&XMAS[..], &reversed] {
+                    let found = (0..word.len())
+                        .all(|k| at(window, k, col + d_col * k as isize) == Some(word[k]));
+                    count += usize::from(found);
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// Number of X-MAS whose lowest letters are on the last row of the window.
+fn x_mas_ending_at(window: &VecDeque<Vec<char>>) -> usize {
+    let Some(up) = window.len().checked_sub(MAS.len()) else {
+        return 0;
+    };
+    let (center, down) = (up + 1, up + 2);
+    let is_mas = |a: Option<char>, b: Option<char>| {
+        (a, b) == (Some(MAS[0]), Some(MAS[2])) || (a, b) == (Some(MAS[2]), Some(MAS[0]))
+    };
+
+    (0..window[center].len() as isize)
+        .filter(|col| at(window, center, *col) == Some(MAS[1]))
+        .filter(|col| is_mas(at(window, up, col - 1), at(window, down, col + 1)))
+        .filter(|col| is_mas(at(window, up, col + 1), at(window, down, col - 1)))
+        .count()
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::stream::{count_stream, Counts, StreamError};
+    use crate::Day4;
+    use aoc_core::Solution;
+
+    const SAMPLE: &str = include_str!("../../inputs/day4/sample.txt");
+
+    #[test]
+    fn tst_stream_sample() {
+        let counts = count_stream(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(counts, Counts { xmas: 18, x_mas: 9 });
+
+        let crlf = format!("\r\n{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+        assert_eq!(count_stream(crlf.as_bytes()).unwrap(), counts);
+
+        // Wider than the window, with words across the mirror line
+        let mirrored = SAMPLE
+            .lines()
+            .map(|line| format!("{line}{}", line.chars().rev().collect::<String>()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let grid = Day4::parse(&mirrored).unwrap();
+        let counts = count_stream(mirrored.as_bytes()).unwrap();
+        assert_eq!(counts.xmas.to_string(), Day4::part1(&grid).unwrap());
+        assert_eq!(counts.x_mas.to_string(), Day4::part2(&grid).unwrap());
+    }
+
+    #[test]
+    fn tst_stream_malformed() {
+        let Err(StreamError::Parse(err)) = count_stream("XMAS\n\nXMA\n".as_bytes()) else {
+            panic!("a short row is rejected");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "XMA"));
+        assert_eq!(err.reason, "expected 4 columns, found 3");
+
+        let Err(StreamError::Parse(err)) = count_stream("XMAS\nXMA\u{308}S".as_bytes()) else {
+            panic!("a letter with a combining accent is rejected");
+        };
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
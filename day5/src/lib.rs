@@ -0,0 +1,214 @@
+use aoc_core::{AnswerError, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day5;
+
+/// Largest page number accepted, as the rules are indexed by page.
+const MAX_PAGE: usize = 9999;
+
+/// Ordering rules, indexed by page, followed by the update requests.
+#[derive(Debug)]
+pub struct Manual {
+    pub rules: Vec<Vec<usize>>,
+    pub requests: Vec<Vec<usize>>,
+}
+
+impl Solution for Day5 {
+    const NUMBER: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().skip_while(|line| line.trim().is_empty());
+        let mut rules = parse_rules(
+            input,
+            lines.by_ref().take_while(|line| !line.trim().is_empty()),
+        )?;
+        let requests = parse_requests(input, lines)?;
+
+        // Pages without any rule still need an entry to be looked up
+        let last_page = requests.iter().flatten().max().copied().unwrap_or_default();
+        if rules.len() <= last_page {
+            rules.resize(last_page + 1, vec![]);
+        }
+
+        Ok(Manual { rules, requests })
+    }
+
+    /// Main body for solving
+    fn part1(manual: &Self::Input) -> Result<String, AnswerError> {
+        let sum = manual
+            .requests
+            .iter()
+            .filter(|request| request_valid(&manual.rules, request))
+            .map(|request| {
+                let middle = request.len() / 2;
+                request[middle]
+            })
+            .sum::<usize>();
+
+        Ok(sum.to_string())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<String, AnswerError> {
+        let sum = manual
+            .requests
+            .iter()
+            .filter(|request| !request_valid(&manual.rules, request))
+            .map(|request| reorder(&manual.rules, request))
+            .map(|request| {
+                let middle = request.len() / 2;
+                request[middle]
+            })
+            .sum::<usize>();
+
+        Ok(sum.to_string())
+    }
+}
+
+fn parse_rules<'a>(
+    input: &str,
+    rules_block: impl Iterator<Item = &'a str>,
+) -> Result<Vec<Vec<usize>>, ParseError> {
+    let mut rules_vec: Vec<Vec<usize>> = vec![];
+
+    for rule in rules_block {
+        let (come_first, come_after) = rule.split_once("|").ok_or_else(|| {
+            ParseError::of(Day5::NUMBER, input, rule, "expected a `before|after` rule")
+        })?;
+        let come_first = parse_page(input, come_first)?;
+        let come_after = parse_page(input, come_after)?;
+
+        if rules_vec.len() <= come_first {
+            rules_vec.resize(come_first + 1, vec![]);
+        }
+        rules_vec[come_first].push(come_after);
+    }
+
+    Ok(rules_vec)
+}
+
+fn parse_requests<'a>(
+    input: &str,
+    requests_block: impl Iterator<Item = &'a str>,
+) -> Result<Vec<Vec<usize>>, ParseError> {
+    requests_block
+        .filter(|request| !request.trim().is_empty())
+        .map(|request| {
+            request
+                .split(",")
+                .map(|page| parse_page(input, page))
+                .collect::<Result<Vec<usize>, _>>()
+        })
+        .collect::<_>()
+}
+
+fn parse_page(input: &str, page: &str) -> Result<usize, ParseError> {
+    match page.parse::<usize>() {
+        Ok(number) if number <= MAX_PAGE => Ok(number),
+        Ok(_) => Err(ParseError::of(
+            Day5::NUMBER,
+            input,
+            page,
+            "page number too large",
+        )),
+        Err(err) => Err(ParseError::of(Day5::NUMBER, input, page, err)),
+    }
+}
+
+fn request_valid(rules: &[Vec<usize>], request: &[usize]) -> bool {
+    let mut processed = HashSet::new();
+
+    request
+        .iter()
+        .map(|page| {
+            let page_rule = rules.get(*page).unwrap();
+            let invalid = &page_rule.iter().any(|rule| processed.contains(rule));
+            processed.insert(page);
+            *invalid
+        })
+        .all(|b| !b)
+}
+
+fn reorder(rules: &[Vec<usize>], request: &[usize]) -> Vec<usize> {
+    let mut new_request = Vec::with_capacity(request.len());
+
+    request.iter().for_each(|page| {
+        let page_rule = rules.get(*page).unwrap();
+        let invalid = &new_request
+            .iter()
+            .position(|rule| page_rule.iter().any(|x| x == rule));
+
+        if let Some(pos) = invalid {
+            new_request.insert(*pos, *page);
+        } else {
+            new_request.push(*page);
+        }
+    });
+
+    new_request
+}
+
+#[cfg(test)]
+mod validation {
+    use crate::{reorder, request_valid, Day5};
+    use aoc_core::Solution;
+
+    #[test]
+    fn tst_validate_request() {
+        let rules = vec![
+            vec![3], // 0 is before 3
+            vec![0], // 1 is before 0
+            vec![],  // no rules for 2
+            vec![2], // 3 is before 2
+        ];
+
+        assert!(request_valid(&rules, &[1, 0, 3, 2]));
+        assert!(request_valid(&rules, &[1]));
+        assert!(request_valid(&rules, &[3, 2]));
+        assert!(request_valid(&rules, &[2]));
+        assert!(!request_valid(&rules, &[2, 3]));
+    }
+
+    #[test]
+    fn tst_reorder() {
+        let rules = vec![
+            vec![3], // 0 is before 3
+            vec![0], // 1 is before 0
+            vec![],  // no rules for 2
+            vec![2], // 3 is before 2
+        ];
+
+        assert_eq!(reorder(&rules, &[2, 3]), vec![3, 2]);
+        assert_eq!(reorder(&rules, &[0, 1, 2, 3]), vec![1, 0, 3, 2]);
+    }
+
+    #[test]
+    fn case_crlf() {
+        let input = include_str!("../../inputs/day5/sample.txt");
+
+        assert_eq!(
+            Day5::solve(&input.replace("\n", "\r\n")),
+            Day5::solve(input)
+        );
+
+        let err = Day5::parse("47|53\n97-13\n\n75,47").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "97-13"));
+
+        let err = Day5::parse("47|53\n\n75,47,\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 7, ""));
+
+        // Pages are bounded rather than allocating rules up to them
+        let err = Day5::parse("99999999999|1\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.reason, "page number too large");
+
+        let err = Day5::parse("18446744073709551615|1\n\n1").unwrap_err();
+        assert_eq!(err.text, "18446744073709551615");
+        assert_eq!(err.reason, "page number too large");
+
+        let err = Day5::parse("1|2\n\n1,10000").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+}